@@ -1,26 +1,129 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+use crate::{constants::DEFAULT_ALLOWED_HOSTS, lint::LinterConfig};
+
+/// Other formats for our output, besides markdown and log messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// A bordered table with a row per listing, for reviewing in a terminal
+    Table,
+    /// Errors and warnings as JSON language server protocol diagnostics, for editor integrations
+    Lsp,
+    /// An RSS 2.0 feed with an item per listing, for following the events in a feed reader
+    Rss,
+}
 
 #[derive(Parser, Debug)]
 pub struct Args {
-    /// Markdown file to lint
-    #[arg(short, long)]
-    file: PathBuf,
+    /// Markdown file to lint, gzipped drafts (.md.gz) are read when built with the gzip feature. Can also be a directory
+    /// with the events section split into a file per region, e.g. "virtual.md", plus a "header.md" with the date range
+    #[arg(short, long, required_unless_present = "url")]
+    file: Option<PathBuf>,
+    /// URL to fetch the markdown to lint from instead of a file, e.g. the raw file from a draft PR. Must be https,
+    /// and needs the fetch feature
+    #[arg(long, conflicts_with = "file")]
+    url: Option<String>,
+    /// Lint the file again each time it changes, only re-parsing the lines that changed
+    #[arg(short, long, default_value_t = false, conflicts_with = "url")]
+    watch: bool,
     /// Enable debug logging
     #[arg(short, long, default_value_t = false)]
     debug: bool,
     /// Make edits to the file - the file itself isn't altered but the new draft with edits is printed to stdout
     #[arg(short, long, default_value_t = false)]
     edit: bool,
-    /// Error limit before bailing - otherwise you could have a lot of output if the linter gets in a weird state
+    /// Error limit before bailing - otherwise you could have a lot of output if the linter gets in a weird state. 0 for no limit
     #[arg(short = 'l', long, default_value_t = 20)]
     error_limit: u32,
+    /// When making edits, rewrite http links in the events section to https
+    #[arg(long, default_value_t = false)]
+    upgrade_http: bool,
+    /// When making edits, alphabetize the groups listed for each event
+    #[arg(long, default_value_t = false)]
+    sort_organizers: bool,
+    /// When making edits, put the events in each region in date order rather than failing on events out of order. The
+    /// groups within each event stay in the order they're written unless --sort-organizers is also passed
+    #[arg(long, default_value_t = false)]
+    sort_events: bool,
+    /// Treat the end date of the newsletter's date range as exclusive, by default events on the end date are included
+    #[arg(long, default_value_t = false)]
+    range_exclusive_end: bool,
+    /// Only check the structure of the events section (headers, overviews, and event lines), skipping any checks that
+    /// need the date range
+    #[arg(long, default_value_t = false)]
+    structure_only: bool,
+    /// After an error, skip ahead to the next region header rather than trying to lint the following lines
+    #[arg(long, default_value_t = false)]
+    resync_on_region: bool,
+    /// Fail if the events section doesn't have any events, rather than just warning
+    #[arg(long, default_value_t = false)]
+    require_events: bool,
+    /// Treat warnings as errors, failing the lint if there are any
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
+    /// Warn about events with the same group and title as an earlier event, e.g. a weekly event listed for each week
+    #[arg(long, default_value_t = false)]
+    flag_recurring: bool,
+    /// Warn about event titles naming a month the event isn't in, e.g. a "November Meetup" listed in October
+    #[arg(long, default_value_t = false)]
+    cross_check_dates: bool,
+    /// Only report issues on lines changed since this git ref (e.g. "main"), so stricter lints can be adopted for new
+    /// edits first. The file must be a plain markdown file tracked by git
+    #[arg(long, value_name = "GIT_REF", conflicts_with = "url")]
+    since: Option<String>,
+    /// Warn if the newsletter's date range spans more than this many days
+    #[arg(long, default_value_t = 40)]
+    max_range_days: u32,
+    /// Warn about events more than this many days after the newsletter's date range
+    #[arg(long, default_value_t = 365)]
+    max_days_after_range: u32,
+    /// Only print the number of events, in total and by region. Lint issues don't fail the run unless we can't read
+    /// the events section at all
+    #[arg(long, default_value_t = false, conflicts_with = "watch")]
+    count_only: bool,
+    /// Only output the listings for this region (e.g. "Europe") after linting
+    #[arg(long)]
+    region: Option<String>,
+    /// Output the listings grouped by ISO week within each region after linting, e.g. for a calendar view
+    #[arg(long, default_value_t = false)]
+    group_by_week: bool,
+    /// Output all the listings in date order after linting, with the region noted on each, e.g. for a chronological
+    /// newsletter
+    #[arg(long, default_value_t = false)]
+    group_by_date: bool,
+    /// List the events on the first day of the newsletter's date range after linting, these are often recurring events
+    /// worth double checking
+    #[arg(long, default_value_t = false)]
+    list_first_day: bool,
+    /// Output the earliest and latest event date in each region after linting, and whether they're within the
+    /// newsletter's date range
+    #[arg(long, default_value_t = false)]
+    region_date_spans: bool,
+    /// List each group's link and name after linting, to spot a misattributed link or a region dominated by one group
+    #[arg(long, default_value_t = false)]
+    list_organizers: bool,
+    /// Output the listings or diagnostics in another format after linting
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+    /// Only allow links to these hosts (comma separated). Without any hosts given, a default list of common event
+    /// hosting sites is used
+    #[arg(long, num_args = 0.., value_delimiter = ',')]
+    allowed_hosts: Option<Vec<String>>,
 }
 
 impl Args {
-    pub fn file(&self) -> &PathBuf {
-        &self.file
+    pub fn file(&self) -> Option<&PathBuf> {
+        self.file.as_ref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn watch(&self) -> bool {
+        self.watch
     }
 
     pub fn debug(&self) -> bool {
@@ -34,4 +137,125 @@ impl Args {
     pub fn error_limit(&self) -> u32 {
         self.error_limit
     }
+
+    pub fn upgrade_http(&self) -> bool {
+        self.upgrade_http
+    }
+
+    pub fn sort_organizers(&self) -> bool {
+        self.sort_organizers
+    }
+
+    pub fn sort_events(&self) -> bool {
+        self.sort_events
+    }
+
+    pub fn range_exclusive_end(&self) -> bool {
+        self.range_exclusive_end
+    }
+
+    pub fn structure_only(&self) -> bool {
+        self.structure_only
+    }
+
+    pub fn resync_on_region(&self) -> bool {
+        self.resync_on_region
+    }
+
+    pub fn require_events(&self) -> bool {
+        self.require_events
+    }
+
+    pub fn deny_warnings(&self) -> bool {
+        self.deny_warnings
+    }
+
+    pub fn flag_recurring(&self) -> bool {
+        self.flag_recurring
+    }
+
+    pub fn cross_check_dates(&self) -> bool {
+        self.cross_check_dates
+    }
+
+    pub fn since(&self) -> Option<&str> {
+        self.since.as_deref()
+    }
+
+    pub fn max_range_days(&self) -> u32 {
+        self.max_range_days
+    }
+
+    pub fn max_days_after_range(&self) -> u32 {
+        self.max_days_after_range
+    }
+
+    pub fn count_only(&self) -> bool {
+        self.count_only
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn group_by_week(&self) -> bool {
+        self.group_by_week
+    }
+
+    pub fn group_by_date(&self) -> bool {
+        self.group_by_date
+    }
+
+    pub fn list_first_day(&self) -> bool {
+        self.list_first_day
+    }
+
+    pub fn region_date_spans(&self) -> bool {
+        self.region_date_spans
+    }
+
+    pub fn list_organizers(&self) -> bool {
+        self.list_organizers
+    }
+
+    pub fn format(&self) -> Option<OutputFormat> {
+        self.format
+    }
+
+    /// Hosts links are allowed to point to, if we are checking hosts at all
+    pub fn allowed_hosts(&self) -> Option<Vec<String>> {
+        self.allowed_hosts.as_ref().map(|hosts| {
+            if hosts.is_empty() {
+                DEFAULT_ALLOWED_HOSTS
+                    .iter()
+                    .map(|host| host.to_string())
+                    .collect()
+            } else {
+                hosts.clone()
+            }
+        })
+    }
+
+    /// Linter options from our command line args
+    pub fn linter_config(&self) -> LinterConfig {
+        LinterConfig {
+            should_edit: self.edit,
+            error_limit: self.error_limit,
+            upgrade_http: self.upgrade_http,
+            sort_organizers: self.sort_organizers,
+            sort_events: self.sort_events,
+            range_exclusive_end: self.range_exclusive_end,
+            structure_only: self.structure_only,
+            resync_on_region: self.resync_on_region,
+            require_events: self.require_events,
+            max_range_days: self.max_range_days,
+            max_days_after_range: self.max_days_after_range,
+            allowed_hosts: self.allowed_hosts(),
+            deny_warnings: self.deny_warnings,
+            flag_recurring: self.flag_recurring,
+            cross_check_dates: self.cross_check_dates,
+            // filled in from git when linting, see `since`
+            changed_lines: None,
+        }
+    }
 }
@@ -1,16 +1,34 @@
 use std::sync::LazyLock;
 
-use url::Host;
+use url::{Host, Url};
+
+use crate::{
+    lint::LintError,
+    regex::{EVENTBRITE_PATH_RE, LUMA_PATH_RE},
+};
 
 /// Lines we expect to match exactly
 pub(crate) const START_EVENTS_SECTION: &str = "## Upcoming Events";
 pub(crate) const EVENT_REGION_HEADER: &str = "### ";
 pub(crate) const END_EVENTS_SECTION: &str =
     "If you are running a Rust event please add it to the [calendar]";
+/// The full footer at the end of the events section, used when rendering the section back out
+pub(crate) const EVENTS_FOOTER: &str =
+    "If you are running a Rust event please add it to the [calendar] to get
+it mentioned here. Please remember to add a link to the event too.
+Email the [Rust Community Team][community] for access.";
 
 /// Hints for what type of line we are parsing - this helps us generate a bit better error messages
 pub(crate) const EVENTS_DATE_RANGE_HINT: &str = "Rusty Events between";
+/// The date range line is expected to end with our friend Ferris
+pub(crate) const CRAB_EMOJI: &str = "🦀";
 pub(crate) const EVENT_NAME_HINT: &str = "    * [**";
+/// Indentation for event names under their overview line
+pub(crate) const EVENT_NAME_INDENT: &str = "    ";
+
+/// Inline comment directive to skip lints on the following line, e.g. "<!-- twir-lint-ignore EventOutOfOrder -->"
+pub(crate) const IGNORE_DIRECTIVE_START: &str = "<!-- twir-lint-ignore";
+pub(crate) const IGNORE_DIRECTIVE_END: &str = "-->";
 
 /// Line "types" in the event section. We use this in several different stringy contexts, so just hardcode the strings here
 /// See EventLineType for a description of each type
@@ -21,8 +39,32 @@ pub(crate) const EVENT_REGION_HEADER_TYPE: &str = "EventRegionHeader";
 pub(crate) const EVENT_DATE_LOCATION_GROUP_TYPE: &str = "EventDateLocationGroup";
 pub(crate) const EVENT_NAME_TYPE: &str = "EventName";
 pub(crate) const END_EVENT_SECTION_TYPE: &str = "EndEventSection";
+pub(crate) const IGNORE_DIRECTIVE_TYPE: &str = "IgnoreDirective";
 pub(crate) const UNRECOGNIZED_TYPE: &str = "Unrecognized";
 
+/// Delimiter between the first and last day of a multi-day event, e.g. "2024-11-06 - 2024-11-08"
+pub(crate) const EVENT_DATE_RANGE_DELIM: &str = " - ";
+/// Delimiter between the days of a recurring event listed once, e.g. "2024-10-24, 2024-10-31"
+pub(crate) const EVENT_DATES_DELIM: &str = ", ";
+
+/// Non-breaking space, sometimes pasted in where we expect a regular space
+pub(crate) const NON_BREAKING_SPACE: char = '\u{a0}';
+
+/// Zero-width spaces, joiners, and byte order marks, which are invisible but make otherwise identical text compare
+/// differently
+pub(crate) const ZERO_WIDTH_CHARACTERS: &[char] =
+    &['\u{200b}', '\u{200c}', '\u{200d}', '\u{2060}', '\u{feff}'];
+
+/// Month names that are also ordinary words, e.g. "You May Like Rust", so we only take them as months next to a day
+pub(crate) const AMBIGUOUS_MONTHS: &[&str] = &["March", "May"];
+
+/// Characters that look like an apostrophe, usually from smart quotes in a word processor
+pub(crate) const APOSTROPHE_LOOKALIKES: &[char] = &['\u{2019}', '\u{2018}', '\u{02bc}', '\u{2032}'];
+
+/// Location for virtual events, and the synonym people sometimes use instead
+pub(crate) const VIRTUAL_LOCATION: &str = "Virtual";
+pub(crate) const ONLINE_LOCATION: &str = "Online";
+
 /// Regions from headers, e.g. "Virtual", "Asia", "Europe", etc.
 pub(crate) const REGIONS: &[&str] = &[
     "Virtual",
@@ -33,8 +75,69 @@ pub(crate) const REGIONS: &[&str] = &[
     "Oceania",
 ];
 
+/// Start of markdown link targets, used for upgrading http links to https
+pub(crate) const HTTP_LINK_START: &str = "](http://";
+pub(crate) const HTTPS_LINK_START: &str = "](https://";
+
+/// Hosts we allow links to when checking hosts against an allowlist, if the user doesn't give us their own
+pub(crate) const DEFAULT_ALLOWED_HOSTS: &[&str] = &[
+    "meetup.com",
+    "lu.ma",
+    "eventbrite.com",
+    "eventbrite.fr",
+    "eventbrite.co.uk",
+];
+
 /// The meetup.com domain (needs String, hence the LazyLock)
 pub(crate) static MEETUP_DOMAIN: LazyLock<Host> =
     LazyLock::new(|| Host::Domain("www.meetup.com".to_owned()));
+/// meetup.com without the "www.", which serves the same pages
+pub(crate) const MEETUP_BARE_DOMAIN: &str = "meetup.com";
 /// The tracker that is sometimes included in the meetup urls
 pub(crate) const MEETUP_TRACKER: &str = "eventOrigin";
+
+/// URL rules for an event hosting platform, checked for every link to one of its hosts
+pub(crate) struct PlatformUrlRule {
+    /// Hosts the platform serves pages from, without any "www."
+    pub(crate) hosts: &'static [&'static str],
+    pub(crate) validate: fn(&Url) -> Result<(), LintError>,
+}
+
+/// The event hosting platforms we know the URLs of, add new platforms here
+pub(crate) const PLATFORM_URL_RULES: &[PlatformUrlRule] = &[
+    PlatformUrlRule {
+        hosts: &[MEETUP_BARE_DOMAIN],
+        validate: |url| match url.query() {
+            Some(query) if query.contains(MEETUP_TRACKER) => {
+                Err(LintError::UrlContainsTracker(url.clone()))
+            }
+            _ => Ok(()),
+        },
+    },
+    PlatformUrlRule {
+        hosts: &["lu.ma"],
+        validate: |url| {
+            if LUMA_PATH_RE.is_match(url.path()) {
+                Ok(())
+            } else {
+                Err(LintError::MalformedPlatformUrl {
+                    url: url.to_string(),
+                    expected: "https://lu.ma/<event>",
+                })
+            }
+        },
+    },
+    PlatformUrlRule {
+        hosts: &["eventbrite.com", "eventbrite.fr", "eventbrite.co.uk"],
+        validate: |url| {
+            if EVENTBRITE_PATH_RE.is_match(url.path()) {
+                Ok(())
+            } else {
+                Err(LintError::MalformedPlatformUrl {
+                    url: url.to_string(),
+                    expected: "https://www.eventbrite.com/e/<event> or /o/<organizer>",
+                })
+            }
+        },
+    },
+];
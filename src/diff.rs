@@ -0,0 +1,172 @@
+use std::{
+    collections::HashSet,
+    io,
+    path::Path,
+    process::{Command, Output},
+};
+
+use crate::draft::is_plain_draft;
+
+/// Start of a hunk header in a unified diff, e.g. "@@ -12,2 +12,3 @@"
+const HUNK_HEADER_START: &str = "@@ ";
+
+/// Lines of the new version of a file that a unified diff adds or changes, numbered from 1. Removed lines aren't in the
+/// new version, so they don't count
+pub fn changed_lines(diff: &str) -> HashSet<usize> {
+    diff.lines()
+        .filter_map(|line| line.strip_prefix(HUNK_HEADER_START))
+        .filter_map(|header| {
+            // the new side of the hunk, "+start,count" or just "+start" for a single line
+            let new_side = header
+                .split_whitespace()
+                .find(|part| part.starts_with('+'))?;
+            let (start, count) = match new_side[1..].split_once(',') {
+                Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+                None => (new_side[1..].parse().ok()?, 1),
+            };
+            Some(start..start + count)
+        })
+        .flatten()
+        .collect()
+}
+
+/// Lines of a file that changed since the given git ref, e.g. "main" or "HEAD~1". An empty set means nothing changed,
+/// so it's an error if we can't diff the file at all: a gzipped draft or a directory of region files whose line numbers
+/// aren't the file's, a file git doesn't track, or no git
+pub fn git_changed_lines(path: &Path, git_ref: &str) -> io::Result<HashSet<usize>> {
+    if !is_plain_draft(path) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "can't diff '{}' by line, it isn't a plain markdown file",
+                path.display()
+            ),
+        ));
+    }
+
+    // git diff has no output for an untracked file, which would look like nothing changed
+    let tracked = git(&["ls-files", "--error-unmatch"], path)?;
+    if !tracked.status.success() {
+        return Err(io::Error::other(format!(
+            "'{}' isn't tracked by git, so there's nothing to diff it against",
+            path.display()
+        )));
+    }
+
+    let output = git(&["diff", "--no-color", "--unified=0", git_ref], path)?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "git diff against '{}' failed: {}",
+            git_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(changed_lines(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Runs a git command on a file, with a clearer error if git isn't installed
+fn git(args: &[&str], path: &Path) -> io::Result<Output> {
+    Command::new("git")
+        .args(args)
+        .arg("--")
+        .arg(path)
+        .output()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => io::Error::new(
+                io::ErrorKind::NotFound,
+                "git isn't installed, it's needed to find the changed lines",
+            ),
+            _ => e,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lint::{EventSectionLinter, LintError, LinterConfig};
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    #[test]
+    fn test_changed_lines() {
+        let diff = concat!(
+            "diff --git a/draft.md b/draft.md\n",
+            "--- a/draft.md\n",
+            "+++ b/draft.md\n",
+            "@@ -3 +3 @@ Some context\n",
+            "-old\n",
+            "+new\n",
+            "@@ -10,0 +11,2 @@\n",
+            "+added\n",
+            "+added\n",
+            "@@ -20,3 +21,0 @@\n",
+            "-removed\n",
+        );
+        let mut lines: Vec<usize> = changed_lines(diff).into_iter().collect();
+        lines.sort();
+        assert_eq!(lines, vec![3, 11, 12]);
+    }
+
+    #[test]
+    fn test_only_changed_lines_reported() -> TestResult {
+        // two events with problems, but only the second one was touched
+        let text = SAMPLE
+            .replace(
+                "[**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)",
+                "[**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](http://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .replace(
+                "[**Embedded Rust Workshop**](https://www.meetup.com/vancouver-rust/events/304047664/)",
+                "[**Embedded Rust Workshop**](http://www.meetup.com/vancouver-rust/events/304047664/)",
+            );
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        let warned: Vec<usize> = linter.warnings().iter().map(|w| w.line_num()).collect();
+        assert!(warned.contains(&159) && warned.contains(&195));
+
+        let diff = "@@ -195 +195 @@\n-    * [**Embedded Rust Workshop**](https://...)\n+    * [**Embedded Rust Workshop**](http://...)\n";
+        let config = LinterConfig {
+            changed_lines: Some(changed_lines(diff)),
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 195);
+        assert!(matches!(
+            linter.warnings()[0].error(),
+            LintError::NonHttpsUrl(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_changed_lines_needs_plain_file() {
+        for path in ["test", "test/570.md.gz"] {
+            let result = git_changed_lines(Path::new(path), "HEAD");
+            assert_eq!(
+                result.map_err(|e| e.kind()),
+                Err(io::ErrorKind::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_git_changed_lines_untracked() -> TestResult {
+        let path = std::env::temp_dir().join(format!(
+            "twir-events-lint-{}-untracked.md",
+            std::process::id()
+        ));
+        std::fs::write(&path, SAMPLE)?;
+
+        // there's no diff for a file git doesn't know about, which mustn't look like an unchanged file
+        let result = git_changed_lines(&path, "HEAD");
+        std::fs::remove_file(&path)?;
+        assert!(result.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use std::{fs, io, path::Path};
+
+use log::warn;
+use url::Url;
+
+use crate::constants::{EVENTS_FOOTER, REGIONS};
+
+/// File extension for gzip-compressed drafts, e.g. historical archives stored as `.md.gz`
+const GZIP_EXTENSION: &str = "gz";
+
+/// File extension for the files in a directory of region files
+const MARKDOWN_EXTENSION: &str = "md";
+
+/// The file with the start of the events section in a directory of region files
+const SECTION_HEADER_FILE: &str = "header.md";
+
+/// Reads a draft into a string, decompressing it first if it's gzipped. A directory is read as an events section split
+/// into region files, see `read_draft_dir`
+pub fn read_draft(path: &Path) -> io::Result<String> {
+    if path.is_dir() {
+        read_draft_dir(path)
+    } else if path.extension().is_some_and(|ext| ext == GZIP_EXTENSION) {
+        read_gzipped(path)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Whether the draft at this path is read as it is, rather than decompressed or put together from region files, so its
+/// line numbers are the file's own
+pub fn is_plain_draft(path: &Path) -> bool {
+    !path.is_dir() && path.extension().is_none_or(|ext| ext != GZIP_EXTENSION)
+}
+
+/// Reads an events section split into a file per region, putting it back together as a single section. The directory
+/// has a "header.md" with the "## Upcoming Events" header and date range, then a file for each region named after it,
+/// e.g. "virtual.md" or "north-america.md", with the region header and its events. Regions are put in the usual order
+/// and the footer is added after them. Line numbers are for the combined section rather than the region files. Any
+/// other markdown file is an error, since it's probably a region file with a typo in its name
+pub fn read_draft_dir(dir: &Path) -> io::Result<String> {
+    let region_files: Vec<String> = REGIONS
+        .iter()
+        .map(|region| region_file_name(region))
+        .collect();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if file_name == SECTION_HEADER_FILE || region_files.iter().any(|name| name == file_name) {
+            continue;
+        }
+
+        if path
+            .extension()
+            .is_some_and(|ext| ext == MARKDOWN_EXTENSION)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "'{}' isn't named after a region, expected one of {:?}",
+                    path.display(),
+                    region_files
+                ),
+            ));
+        }
+        warn!("Skipping '{}', it isn't a region file", path.display());
+    }
+
+    let mut parts = vec![fs::read_to_string(dir.join(SECTION_HEADER_FILE))?];
+
+    for region in REGIONS {
+        let path = dir.join(region_file_name(region));
+        if path.exists() {
+            parts.push(fs::read_to_string(path)?);
+        }
+    }
+    parts.push(EVENTS_FOOTER.to_owned());
+
+    let parts: Vec<&str> = parts.iter().map(|part| part.trim()).collect();
+    Ok(format!("{}\n", parts.join("\n\n")))
+}
+
+/// File name for a region's events in a directory of region files, e.g. "north-america.md"
+fn region_file_name(region: &str) -> String {
+    format!(
+        "{}.{}",
+        region.to_lowercase().replace(' ', "-"),
+        MARKDOWN_EXTENSION
+    )
+}
+
+#[cfg(feature = "gzip")]
+fn read_gzipped(path: &Path) -> io::Result<String> {
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    let mut md = String::new();
+    GzDecoder::new(fs::File::open(path)?).read_to_string(&mut md)?;
+    Ok(md)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn read_gzipped(path: &Path) -> io::Result<String> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "'{}' looks gzipped, rebuild with the 'gzip' feature to read it",
+            path.display()
+        ),
+    ))
+}
+
+/// Fetches a draft, only over https
+pub fn fetch_draft(url: &str) -> io::Result<String> {
+    let url = Url::parse(url).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    if url.scheme() != "https" && !allows_plain_http(&url) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{}' is not an https url", url),
+        ));
+    }
+
+    fetch(&url)
+}
+
+/// Tests fetch from a local server, which can't serve https, so they're allowed plain http to loopback addresses
+#[cfg(test)]
+fn allows_plain_http(url: &Url) -> bool {
+    let is_loopback = match url.host() {
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        Some(url::Host::Domain(domain)) => domain == "localhost",
+        None => false,
+    };
+    url.scheme() == "http" && is_loopback
+}
+
+#[cfg(not(test))]
+fn allows_plain_http(_url: &Url) -> bool {
+    false
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(url: &Url) -> io::Result<String> {
+    ureq::get(url.as_str())
+        .call()
+        .map_err(io::Error::other)?
+        .into_string()
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch(url: &Url) -> io::Result<String> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("can't fetch '{}', rebuild with the 'fetch' feature", url),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lint::EventSectionLinter;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    /// A path in the temp dir for a test's files, unique to the test and this test run so tests can run in parallel
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("twir-events-lint-{}-{}", std::process::id(), name))
+    }
+
+    /// Writes a directory of region files with a header and the Virtual region
+    fn write_region_dir(dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        fs::write(
+            dir.join("header.md"),
+            "## Upcoming Events\n\nRusty Events between 2024-10-23 - 2024-11-20 🦀\n",
+        )?;
+        fs::write(
+            dir.join("virtual.md"),
+            "### Virtual\n* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n    * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)\n",
+        )
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_read_gzipped_draft() -> TestResult {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let path = temp_path("570.md.gz");
+        let mut encoder = GzEncoder::new(fs::File::create(&path)?, Compression::default());
+        encoder.write_all(SAMPLE.as_bytes())?;
+        encoder.finish()?;
+
+        let md = read_draft(&path)?;
+        fs::remove_file(&path)?;
+        assert_eq!(md, SAMPLE);
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&md)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_plain_draft() -> TestResult {
+        let md = read_draft(Path::new("test/570.md"))?;
+        assert_eq!(md, SAMPLE);
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&md)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_draft_dir() -> TestResult {
+        let dir = temp_path("read-draft-dir");
+        fs::create_dir_all(&dir)?;
+        // written out of order, they should still be read in the usual region order
+        fs::write(
+            dir.join("north-america.md"),
+            "### North America\n* 2024-10-23 | Austin, TX, US | [Rust ATX](https://www.meetup.com/rust-atx/)\n    * [**Rust Lunch - Fareground**](https://www.meetup.com/rust-atx/events/304128233/)\n",
+        )?;
+        write_region_dir(&dir)?;
+
+        let md = read_draft(&dir)?;
+        fs::remove_dir_all(&dir)?;
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&md)?;
+        let regions: Vec<&str> = linter.events().regions().collect();
+        assert_eq!(regions, vec!["Virtual", "North America"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_draft_dir_unknown_region() -> TestResult {
+        let dir = temp_path("read-draft-dir-unknown-region");
+        write_region_dir(&dir)?;
+        fs::write(dir.join("north-amercia.md"), "### North America\n")?;
+
+        let result = read_draft(&dir);
+        fs::remove_dir_all(&dir)?;
+
+        let e = result.err().ok_or("expected an error")?;
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(e.to_string().contains("north-amercia.md"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_requires_https() {
+        let result = fetch_draft("http://example.com/570.md");
+        assert_eq!(
+            result.map_err(|e| e.kind()),
+            Err(io::ErrorKind::InvalidInput)
+        );
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_fetch_draft() -> TestResult {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread,
+        };
+
+        // a tiny server that answers a single request with our sample
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+        let server = thread::spawn(move || -> io::Result<()> {
+            let (mut stream, _) = listener.accept()?;
+            let mut reader = BufReader::new(stream.try_clone()?);
+            let mut header_line = String::new();
+            while reader.read_line(&mut header_line)? > 2 {
+                header_line.clear();
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/markdown; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                SAMPLE.len(),
+                SAMPLE
+            )
+        });
+
+        let md = fetch_draft(&format!("http://127.0.0.1:{}/570.md", port))?;
+        server.join().map_err(|_| "server panicked")??;
+        assert_eq!(md, SAMPLE);
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&md)?;
+        Ok(())
+    }
+}
@@ -1,31 +1,280 @@
 use std::{fmt, str::FromStr};
 
-use chrono::{NaiveDate, ParseError};
-use log::{debug, warn};
+use chrono::{Datelike, Months, NaiveDate, ParseError};
+use log::debug;
 use regex::Regex;
 use url::Url;
 
-use crate::{constants::*, lint::LintError, regex::*};
+use crate::{
+    constants::*,
+    events::{EventGroups, EventOverview, Events, MarkdownLink},
+    lint::LintError,
+    regex::*,
+};
+
+/// A region header for a region we don't know about, with the known region it's closest to if it looks like a typo
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionParseError {
+    region: String,
+    suggestion: Option<&'static str>,
+}
+
+impl RegionParseError {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_owned(),
+            suggestion: EventLineType::closest_region(region),
+        }
+    }
+
+    /// The region we couldn't parse
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The known region closest to the one we couldn't parse, if any are close
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+impl fmt::Display for RegionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.suggestion {
+            Some(suggestion) => write!(
+                f,
+                "Found unknown region: '{}', did you mean '{}'?\nExpected one of '{:?}'",
+                self.region, suggestion, REGIONS
+            ),
+            None => write!(
+                f,
+                "Found unknown region: '{}'\nExpected one of '{:?}'",
+                self.region, REGIONS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegionParseError {}
+
+/// Parses a region name, ignoring case and extra whitespace between words, e.g. "north america" is "North America"
+pub fn parse_region(region: &str) -> Result<&'static str, RegionParseError> {
+    EventLineType::known_region(region).ok_or_else(|| RegionParseError::new(region))
+}
+
+/// Collapses runs of whitespace into single spaces and trims the ends, e.g. "North  America" to "North America"
+pub(crate) fn normalize_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Replaces apostrophe lookalikes with a plain "'", so names that only differ by apostrophe compare equal
+pub(crate) fn normalize_apostrophes(s: &str) -> String {
+    s.replace(APOSTROPHE_LOOKALIKES, "'")
+}
+
+/// An event's date, a single day, a range of days for multi-day events like conferences, or a list of days for
+/// recurring events
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventDate {
+    Date(NaiveDate),
+    DateRange(NaiveDate, NaiveDate),
+    Multiple(Vec<NaiveDate>),
+}
+
+impl EventDate {
+    /// The date we sort and compare events by, the first day of the event
+    pub fn sort_date(&self) -> NaiveDate {
+        match self {
+            Self::Date(date) => *date,
+            Self::DateRange(start, _) => *start,
+            // we never parse an empty list, but fall back to something sortable just in case
+            Self::Multiple(dates) => dates.iter().min().copied().unwrap_or_default(),
+        }
+    }
+
+    /// The days written out for the event. For a range that's just its first and last days
+    pub fn days(&self) -> Vec<NaiveDate> {
+        match self {
+            Self::Date(date) => vec![*date],
+            Self::DateRange(start, end) => vec![*start, *end],
+            Self::Multiple(dates) => dates.clone(),
+        }
+    }
+
+    /// The days that need to fall within the newsletter's date range: each day of a recurring event, but only the first
+    /// day of a multi-day one, which can run past the end of the range
+    pub fn scheduled_days(&self) -> Vec<NaiveDate> {
+        match self {
+            Self::Multiple(dates) => dates.clone(),
+            _ => vec![self.sort_date()],
+        }
+    }
+
+    /// The months the event takes place in, numbered from 1. For a range that's every month it spans, not just the
+    /// months of its first and last days
+    pub fn months(&self) -> Vec<u32> {
+        match self {
+            Self::DateRange(start, end) => {
+                let mut months = Vec::new();
+                let mut month = start.with_day(1);
+                while let Some(first_day) = month.filter(|first_day| first_day <= end) {
+                    months.push(first_day.month());
+                    month = first_day.checked_add_months(Months::new(1));
+                }
+                months
+            }
+            _ => self.days().iter().map(|day| day.month()).collect(),
+        }
+    }
+
+    /// Whether the event takes place on the given day
+    pub fn includes(&self, day: NaiveDate) -> bool {
+        match self {
+            Self::Date(date) => *date == day,
+            Self::DateRange(start, end) => (*start..=*end).contains(&day),
+            Self::Multiple(dates) => dates.contains(&day),
+        }
+    }
+
+    /// Orders the variants when two events start on the same day, a single day event comes first
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Date(_) => 0,
+            Self::DateRange(..) => 1,
+            Self::Multiple(_) => 2,
+        }
+    }
+}
+
+impl Ord for EventDate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // events starting on the same day are ordered by their end, so same-start ranges sort stably before we fall
+        // back to comparing locations
+        self.sort_date()
+            .cmp(&other.sort_date())
+            .then_with(|| self.rank().cmp(&other.rank()))
+            .then_with(|| self.days().cmp(&other.days()))
+    }
+}
+
+impl PartialOrd for EventDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for EventDate {
+    type Err = LintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |date: &str| {
+            date.parse::<NaiveDate>()
+                .map_err(EventLineType::map_chrono_parse_error)
+        };
+
+        if s.contains(EVENT_DATES_DELIM) {
+            return Ok(Self::Multiple(
+                s.split(EVENT_DATES_DELIM)
+                    .map(parse)
+                    .collect::<Result<Vec<NaiveDate>, LintError>>()?,
+            ));
+        }
+
+        match s.split_once(EVENT_DATE_RANGE_DELIM) {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                // a backwards range would never include any days, and sort by its later day
+                if end < start {
+                    return Err(LintError::BackwardsEventDateRange { start, end });
+                }
+                Ok(Self::DateRange(start, end))
+            }
+            None => Ok(Self::Date(parse(s)?)),
+        }
+    }
+}
+
+impl fmt::Display for EventDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Date(date) => write!(f, "{}", date),
+            Self::DateRange(start, end) => write!(f, "{}{}{}", start, EVENT_DATE_RANGE_DELIM, end),
+            Self::Multiple(dates) => {
+                let dates: Vec<String> = dates.iter().map(|date| date.to_string()).collect();
+                write!(f, "{}", dates.join(EVENT_DATES_DELIM))
+            }
+        }
+    }
+}
 
 /// An event's date and location. Used to ensure our dates are ordered correctly, first by date, then by location
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct EventDateLocation {
-    date: NaiveDate,
+pub struct EventDateLocation {
+    date: EventDate,
     location: String,
 }
 
 impl EventDateLocation {
-    pub fn date(&self) -> &NaiveDate {
+    pub fn date(&self) -> &EventDate {
         &self.date
     }
 
     pub fn location(&self) -> &str {
         &self.location
     }
+
+    /// The time noted for a virtual event in place of a city, e.g. "18:00 UTC" from "Virtual (18:00 UTC)"
+    pub fn time_note(&self) -> Option<&str> {
+        VIRTUAL_TIME_NOTE_RE
+            .captures(&self.location)
+            .and_then(|captures| captures.name(TIME_NOTE))
+            .map(|time_note| time_note.as_str())
+    }
+
+    /// Some submissions say "Online" rather than "Virtual", returns this date location with the canonical name if so
+    pub(crate) fn canonical(&self) -> Option<Self> {
+        let prefix = self.location.get(..ONLINE_LOCATION.len())?;
+        let rest = &self.location[ONLINE_LOCATION.len()..];
+        if !prefix.eq_ignore_ascii_case(ONLINE_LOCATION)
+            || !(rest.is_empty() || rest.starts_with([' ', '(']))
+        {
+            return None;
+        }
+
+        Some(Self {
+            date: self.date.clone(),
+            location: format!("{}{}", VIRTUAL_LOCATION, rest),
+        })
+    }
+}
+
+/// The part of an event overview line we failed to parse, used to say what we expected to find
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseStage {
+    Date,
+    DateDelimiter,
+    Location,
+    LocationDelimiter,
+    FirstLink,
+}
+
+impl fmt::Display for ParseStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let expected = match self {
+            Self::Date => "expected a date like '2024-10-24' after '* '",
+            Self::DateDelimiter => "expected ' | ' after date",
+            Self::Location => "expected a location after ' | '",
+            Self::LocationDelimiter => "expected ' | ' after location",
+            Self::FirstLink => {
+                "expected a group link like '[Rust Berlin](https://...)' after location"
+            }
+        };
+        write!(f, "{}", expected)
+    }
 }
 
 /// The type of a given line of text in the event section
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum EventLineType {
     /// A newline
     Newline,
@@ -36,11 +285,13 @@ pub(crate) enum EventLineType {
     /// Header of a new regional section, "### Virtual", "### Asia"...
     EventRegionHeader(String),
     /// First line of an event with the date, location, and group link "* 2024-10-24 | Virtual | [Women in Rust]..."
-    EventDateLocationGroup(EventDateLocation),
+    EventDateLocationGroup(EventOverview),
     /// Event name and link to specific event " * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**]..."
-    EventName,
+    EventName(Events),
     /// End of the event section "If you are running a Rust event please add..."
     EndEventSection,
+    /// Lints to skip on the next line "<!-- twir-lint-ignore EventOutOfOrder -->"
+    IgnoreDirective(Vec<String>),
     /// A line we don't recognize - should only be lines that are not within the event section
     Unrecognized,
 }
@@ -53,7 +304,7 @@ impl FromStr for EventLineType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parsed = match s {
             _ if s.is_empty() => Self::Newline,
-            _ if s == START_EVENTS_SECTION => Self::StartEventSection,
+            _ if s.eq_ignore_ascii_case(START_EVENTS_SECTION) => Self::StartEventSection,
             s if s.starts_with(EVENTS_DATE_RANGE_HINT) => {
                 let parsed_time_range = Self::extract_date_range(s)?;
                 Self::EventsDateRange(parsed_time_range.0, parsed_time_range.1)
@@ -62,18 +313,31 @@ impl FromStr for EventLineType {
                 let region = Self::extract_and_validate_region_header(s)?;
                 Self::EventRegionHeader(region.to_owned())
             }
+            // a region header with the wrong number of '#' or spaces, the linter warns about these
+            s if s.starts_with('#') && Self::loose_region_header(s).is_some() => {
+                Self::EventRegionHeader(Self::loose_region_header(s).unwrap_or_default().to_owned())
+            }
             s if EVENT_DATE_LOCATION_HINT_RE.is_match(s) => {
-                let (date, location) = Self::extract_and_validate_date_location_group(s)?;
-                Self::EventDateLocationGroup(EventDateLocation {
+                let (date, location, groups) = Self::extract_and_validate_date_location_group(s)?;
+                let date_location = EventDateLocation {
                     date,
                     location: location.to_owned(),
-                })
+                };
+                Self::EventDateLocationGroup(EventOverview::new(date_location, groups))
             }
-            s if s.starts_with(EVENT_NAME_HINT) => {
-                Self::validate_event_name(s)?;
-                Self::EventName
+            s if s.starts_with(EVENT_NAME_HINT) => Self::EventName(Self::validate_event_name(s)?),
+            // an event name indented with the wrong number of spaces, the linter warns about these
+            s if s.starts_with(' ')
+                && s.trim_start_matches(' ')
+                    .starts_with(EVENT_NAME_HINT.trim_start()) =>
+            {
+                let reindented = format!("{}{}", EVENT_NAME_INDENT, s.trim_start_matches(' '));
+                Self::EventName(Self::validate_event_name(&reindented)?)
             }
             _ if s.starts_with(END_EVENTS_SECTION) => Self::EndEventSection,
+            s if s.starts_with(IGNORE_DIRECTIVE_START) => {
+                Self::IgnoreDirective(Self::extract_ignored_lints(s)?)
+            }
             _ => Self::Unrecognized,
         };
 
@@ -90,15 +354,60 @@ impl fmt::Display for EventLineType {
                 &format!("{}({}, {})", EVENTS_DATE_RANGE_TYPE, start, end)
             }
             Self::EventRegionHeader(region) => &format!("{}({})", EVENT_REGION_HEADER_TYPE, region),
-            Self::EventDateLocationGroup(_event_date_location) => EVENT_DATE_LOCATION_GROUP_TYPE, // TODO: fix this
-            Self::EventName => EVENT_NAME_TYPE,
+            Self::EventDateLocationGroup(_overview) => EVENT_DATE_LOCATION_GROUP_TYPE, // TODO: fix this
+            Self::EventName(_events) => EVENT_NAME_TYPE,
             Self::EndEventSection => END_EVENT_SECTION_TYPE,
+            Self::IgnoreDirective(lints) => {
+                &format!("{}({})", IGNORE_DIRECTIVE_TYPE, lints.join(", "))
+            }
             Self::Unrecognized => UNRECOGNIZED_TYPE,
         };
         write!(f, "{}", s)
     }
 }
 
+/// A single line parsed on its own, outside of the linter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedLine(EventLineType);
+
+impl ParsedLine {
+    /// The kind of line this is, e.g. "EventName" or "EventRegionHeader(Virtual)"
+    pub fn line_type(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Why a single line failed to parse
+#[derive(Debug, PartialEq, Eq)]
+pub struct LineParseError(LintError);
+
+impl LineParseError {
+    pub fn error(&self) -> &LintError {
+        &self.0
+    }
+}
+
+impl fmt::Display for LineParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for LineParseError {
+    // our message is the wrapped lint's, so skip straight to whatever caused it
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Parses a single line of a draft without any of the linter's context. This never panics, whatever the input, so it's
+/// safe to throw arbitrary text at, e.g. from a fuzzer
+pub fn try_parse_line(s: &str) -> Result<ParsedLine, LineParseError> {
+    s.parse::<EventLineType>()
+        .map(ParsedLine)
+        .map_err(LineParseError)
+}
+
 impl EventLineType {
     /// Helper for regex errors
     fn map_regex_error(regex: &Regex) -> LintError {
@@ -140,25 +449,83 @@ impl EventLineType {
         Ok((start_parsed, end_parsed))
     }
 
+    /// Extracts the names of the lints to skip from an ignore directive, e.g. "<!-- twir-lint-ignore EventOutOfOrder -->"
+    fn extract_ignored_lints(line: &str) -> Result<Vec<String>, LintError> {
+        let lints = line
+            .strip_prefix(IGNORE_DIRECTIVE_START)
+            .and_then(|rest| rest.strip_suffix(IGNORE_DIRECTIVE_END))
+            .ok_or(LintError::ParseError)?;
+
+        Ok(lints.split_whitespace().map(str::to_owned).collect())
+    }
+
     /// Extracts and validates the region is an expected one in a region header (e.g. "### Virtual")
-    fn extract_and_validate_region_header(line: &str) -> Result<&str, LintError> {
+    /// The region is matched case-insensitively and returned with its canonical casing, the linter warns about the
+    /// casing separately
+    fn extract_and_validate_region_header(line: &str) -> Result<&'static str, LintError> {
         let region = line
             .strip_prefix(EVENT_REGION_HEADER)
             .ok_or(LintError::ParseError)?;
 
-        if !REGIONS.contains(&region) {
-            Err(LintError::UnknownRegion(region.to_owned()))
-        } else {
-            Ok(region)
+        parse_region(region).map_err(LintError::UnknownRegion)
+    }
+
+    /// Matches headers that are almost region headers, like "##Virtual" or "####  Europe"
+    fn loose_region_header(line: &str) -> Option<&'static str> {
+        Self::known_region(line.trim_start_matches('#'))
+    }
+
+    /// Finds the known region matching a region header's text, ignoring case and extra whitespace between words
+    fn known_region(region: &str) -> Option<&'static str> {
+        let region = normalize_whitespace(region);
+        REGIONS
+            .iter()
+            .find(|known| known.eq_ignore_ascii_case(&region))
+            .copied()
+    }
+
+    /// Finds the known region closest to an unknown one by edit distance, so we can suggest it in error messages
+    pub(crate) fn closest_region(region: &str) -> Option<&'static str> {
+        let region = region.to_lowercase();
+        // anything further away than this is probably not a typo of that region
+        let max_distance = (region.chars().count() / 3).max(1);
+
+        REGIONS
+            .iter()
+            .map(|known| (*known, Self::edit_distance(&region, &known.to_lowercase())))
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(known, _)| known)
+    }
+
+    /// Levenshtein distance between two strings
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, a_char) in a.chars().enumerate() {
+            let mut current_row = vec![i + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+                let distance = (previous_row[j] + substitution_cost)
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1);
+                current_row.push(distance);
+            }
+            previous_row = current_row;
         }
+
+        previous_row[b.len()]
     }
 
     /// Extracts date and location from events, also validates group links
     fn extract_and_validate_date_location_group(
         line: &str,
-    ) -> Result<(NaiveDate, &str), LintError> {
+    ) -> Result<(EventDate, &str, EventGroups), LintError> {
         let re = &*EVENT_DATE_LOCATION_RE;
-        let captures = re.captures(line).ok_or_else(|| Self::map_regex_error(re))?;
+        let captures = re
+            .captures(line)
+            .ok_or_else(|| LintError::ParseFailed(Self::failed_stage(line)))?;
 
         debug!("Captured: '{:?}'", &captures);
 
@@ -174,9 +541,7 @@ impl EventLineType {
             .as_str();
         // TODO: validate location formatting
 
-        let date_parsed = date_capture
-            .parse::<NaiveDate>()
-            .map_err(Self::map_chrono_parse_error)?;
+        let date_parsed = date_capture.parse::<EventDate>()?;
 
         // now we will validate the rest of the line with the group names + links. We may have more than one here as well
         let links_capture = captures
@@ -184,22 +549,39 @@ impl EventLineType {
             .ok_or_else(|| Self::map_regex_error(re))?
             .as_str();
 
+        if !links_capture.starts_with('[') {
+            return Err(LintError::ParseFailed(ParseStage::FirstLink));
+        }
+
         // if we have multiple links, we expect them to be delimited with ' + '
-        let links: Vec<&str> = if links_capture.contains(EVENT_DATE_LOCATION_LINK_DELIM) {
-            links_capture
-                .split(EVENT_DATE_LOCATION_LINK_DELIM)
-                .collect()
-        } else {
-            vec![links_capture]
-        };
+        Self::check_link_delimiter(line, links_capture, EVENT_DATE_LOCATION_LINK_DELIM)?;
+        let groups = Self::parse_md_link_list(links_capture, EVENT_DATE_LOCATION_LINK_DELIM)?;
+
+        Ok((date_parsed, location_capture, groups.into()))
+    }
+
+    /// Walks an overview line that didn't match our regex piece by piece to find where it went wrong
+    fn failed_stage(line: &str) -> ParseStage {
+        let rest = line.strip_prefix("* ").unwrap_or(line);
+        let date_len = EVENT_DATE_PREFIX_RE.find(rest).map_or(0, |date| date.end());
+        if date_len == 0 {
+            return ParseStage::Date;
+        }
 
-        Self::validate_markdown_urls(links, false)?;
+        let Some(rest) = rest[date_len..].trim_start().strip_prefix('|') else {
+            return ParseStage::DateDelimiter;
+        };
 
-        Ok((date_parsed, location_capture))
+        match rest.split_once('|') {
+            Some((location, _)) if location.trim().is_empty() => ParseStage::Location,
+            Some(_) => ParseStage::FirstLink,
+            None if rest.trim().is_empty() => ParseStage::Location,
+            None => ParseStage::LocationDelimiter,
+        }
     }
 
     /// Validates event names/links
-    fn validate_event_name(line: &str) -> Result<(), LintError> {
+    fn validate_event_name(line: &str) -> Result<Events, LintError> {
         let re = &*EVENT_NAME_RE;
         let captures = re.captures(line).ok_or_else(|| Self::map_regex_error(re))?;
         debug!("Captured: '{:?}'", &captures);
@@ -210,79 +592,151 @@ impl EventLineType {
             .as_str();
 
         // multiple links here should be ' | ' delimited
-        let links: Vec<&str> = if link_captures.contains(EVENT_NAME_LINK_DELIM) {
-            link_captures.split(EVENT_NAME_LINK_DELIM).collect()
-        } else {
-            vec![link_captures]
-        };
-
-        Self::validate_markdown_urls(links, true)?;
+        Self::check_link_delimiter(line, link_captures, EVENT_NAME_LINK_DELIM)?;
+        let links = Self::parse_md_link_list(link_captures, EVENT_NAME_LINK_DELIM)?
+            .into_iter()
+            .map(Self::validate_bold_label)
+            .collect::<Result<Vec<MarkdownLink>, LintError>>()?;
 
-        Ok(())
+        Ok(links.into())
     }
 
-    /// Validates one or more links are formatted as expected in markdown, e.g. `[My label](https://mylink.test)`
-    // TODO: don't like bool args, clean this up probably. Ok for now since this check is so simple and all the code that
-    // calls this function is right here
-    fn validate_markdown_urls(urls: Vec<&str>, check_label_is_bold: bool) -> Result<(), LintError> {
-        let re = &*MD_LINK_RE;
-        for url in urls {
-            let capture = re.captures(url).ok_or_else(|| LintError::RegexError {
-                regex_string: re.as_str().to_owned(),
-            })?;
-
-            debug!("Captured: '{:?}'", &capture);
-
-            let label = capture
-                .name(LINK_LABEL)
-                .ok_or_else(|| LintError::RegexError {
-                    regex_string: re.as_str().to_owned(),
-                })?
-                .as_str();
-
-            if check_label_is_bold
-                && (&label[0..2] != "**" || &label[label.len() - 2..label.len()] != "**")
-            {
-                return Err(LintError::InvalidLinkLabel(label.to_owned()));
+    /// Makes sure the links from `line` are joined with `delimiter`. Organizers are joined with ' + ' and event links
+    /// with ' | ', which are easy to mix up
+    fn check_link_delimiter(line: &str, links: &str, delimiter: &str) -> Result<(), LintError> {
+        for found in [EVENT_DATE_LOCATION_LINK_DELIM, EVENT_NAME_LINK_DELIM] {
+            if found != delimiter && links.contains(&format!("){}[", found)) {
+                return Err(LintError::WrongDelimiter {
+                    line: line.to_owned(),
+                    expected: delimiter.to_owned(),
+                    found: found.to_owned(),
+                });
             }
-
-            let url = capture
-                .name(LINK)
-                .ok_or_else(|| LintError::RegexError {
-                    regex_string: re.as_str().to_owned(),
-                })?
-                .as_str();
-
-            Self::validate_url(&Url::parse(url).map_err(LintError::InvalidUrl)?)?;
         }
 
         Ok(())
     }
 
-    /// Validates a URL is actually kind of valid and any domain-specific logic can be implemented here
-    fn validate_url(url: &Url) -> Result<(), LintError> {
-        // TODO: probably make this an error just for better visibility? like getting line # in error message
-        if url.scheme() != "https" {
-            warn!(
-                "Unexpected URL protocol '{}' in url '{}'",
-                url.scheme(),
-                url
-            );
-        }
+    /// Parses a list of markdown links separated by `delimiter`, e.g. `[Rust Berlin](https://...) + [OpenTechSchool Berlin](https://...)`
+    pub(crate) fn parse_md_link_list(
+        input: &str,
+        delimiter: &str,
+    ) -> Result<Vec<MarkdownLink>, LintError> {
+        Self::split_outside_links(input, delimiter)
+            .into_iter()
+            .map(Self::parse_md_link)
+            .collect()
+    }
+
+    /// Splits on `delimiter`, but only outside of brackets and parentheses so a label like "[C + Rust]" stays in one
+    /// piece
+    fn split_outside_links<'a>(input: &'a str, delimiter: &str) -> Vec<&'a str> {
+        let mut parts = Vec::new();
+        let mut depth: usize = 0;
+        let mut start = 0;
+        let mut chars = input.char_indices();
 
-        // domain specific logic
-        if let Some(domain) = url.host() {
-            // meetup.com
-            if domain == *MEETUP_DOMAIN {
-                if let Some(query_string) = url.query() {
-                    if query_string.contains(MEETUP_TRACKER) {
-                        return Err(LintError::UrlContainsTracker(url.clone()));
-                    }
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    // skip over whatever is escaped
+                    chars.next();
                 }
+                '[' | '(' => depth += 1,
+                ']' | ')' => depth = depth.saturating_sub(1),
+                // a delimiter can't overlap the one before it, e.g. the second " | " in " | | "
+                _ if depth == 0 && i >= start && input[i..].starts_with(delimiter) => {
+                    parts.push(&input[start..i]);
+                    start = i + delimiter.len();
+                }
+                _ => (),
             }
         }
+        parts.push(&input[start..]);
+        parts
+    }
 
-        Ok(())
+    /// Event titles should be bold, e.g. `[**Rust Hack and Learn**](https://...)`. We're lenient with spaces just inside
+    /// the bold markers, e.g. `** title **`, and tidy them up here. Inline code is fine in titles, but raw html isn't
+    fn validate_bold_label(link: MarkdownLink) -> Result<MarkdownLink, LintError> {
+        let title = link
+            .label()
+            .strip_prefix("**")
+            .and_then(|title| title.strip_suffix("**"))
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .filter(|title| Self::has_allowed_markup(title))
+            .ok_or_else(|| LintError::InvalidLinkLabel(link.label().to_owned()))?;
+
+        Ok(MarkdownLink::new(
+            format!("**{}**", title),
+            link.url().clone(),
+        ))
+    }
+
+    /// Whether a title only uses markup we allow, anything goes inside inline code as long as the backticks are closed
+    fn has_allowed_markup(title: &str) -> bool {
+        let outside_code: String = title.split('`').step_by(2).collect();
+        title.matches('`').count().is_multiple_of(2) && !HTML_TAG_RE.is_match(&outside_code)
+    }
+
+    /// Validates a link is formatted as expected in markdown, e.g. `[My label](https://mylink.test)`
+    fn parse_md_link(input: &str) -> Result<MarkdownLink, LintError> {
+        let re = &*MD_LINK_RE;
+        let capture = re.captures(input).ok_or_else(|| {
+            Self::unterminated_link(input).unwrap_or_else(|| Self::map_regex_error(re))
+        })?;
+
+        debug!("Captured: '{:?}'", &capture);
+
+        let label = capture
+            .name(LINK_LABEL)
+            .ok_or_else(|| Self::map_regex_error(re))?
+            .as_str();
+
+        let url = capture
+            .name(LINK)
+            .ok_or_else(|| Self::map_regex_error(re))?
+            .as_str();
+
+        let url = Url::parse(url).map_err(|e| match e {
+            url::ParseError::RelativeUrlWithoutBase => LintError::RelativeUrl {
+                raw: url.to_owned(),
+            },
+            e => LintError::InvalidUrl(e),
+        })?;
+        Self::validate_url(&url)?;
+        Ok(MarkdownLink::new(label.to_owned(), url))
+    }
+
+    /// Explains why a link didn't parse if it's just missing its closing ']' or ')', which is an easy typo to make
+    fn unterminated_link(input: &str) -> Option<LintError> {
+        let missing = match input.split_once("](") {
+            Some((_, url)) if !url.ends_with(')') => ')',
+            None if input.starts_with('[') && !input.contains(']') => ']',
+            _ => return None,
+        };
+
+        Some(LintError::UnterminatedLink {
+            link: input.to_owned(),
+            missing,
+        })
+    }
+
+    /// Validates a URL is actually kind of valid, with the rules for any event hosting platform it links to
+    fn validate_url(url: &Url) -> Result<(), LintError> {
+        let Some(host) = url.host_str() else {
+            return Ok(());
+        };
+        let host = host.strip_prefix("www.").unwrap_or(host);
+
+        match PLATFORM_URL_RULES
+            .iter()
+            .find(|rule| rule.hosts.contains(&host))
+        {
+            Some(rule) => (rule.validate)(url),
+            None => Ok(()),
+        }
     }
 }
 
@@ -337,10 +791,16 @@ mod test {
             "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)";
         let parsed = line.parse::<EventLineType>()?;
 
-        let expected = EventLineType::EventDateLocationGroup(EventDateLocation {
-            date: "2024-10-24".parse::<NaiveDate>()?,
+        let date_location = EventDateLocation {
+            date: EventDate::Date("2024-10-24".parse::<NaiveDate>()?),
             location: "Virtual".to_owned(),
-        });
+        };
+        let groups = vec![MarkdownLink::new(
+            "Women in Rust".to_owned(),
+            Url::parse("https://www.meetup.com/women-in-rust/")?,
+        )];
+        let expected =
+            EventLineType::EventDateLocationGroup(EventOverview::new(date_location, groups.into()));
 
         assert_eq!(parsed, expected);
         Ok(())
@@ -350,7 +810,12 @@ mod test {
     fn test_event_name() -> TestResult {
         let line = "    * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)";
         let parsed = line.parse::<EventLineType>()?;
-        assert_eq!(parsed, EventLineType::EventName);
+
+        let events = vec![MarkdownLink::new(
+            "**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**".to_owned(),
+            Url::parse("https://www.meetup.com/women-in-rust/events/303213835/")?,
+        )];
+        assert_eq!(parsed, EventLineType::EventName(events.into()));
         Ok(())
     }
 
@@ -374,7 +839,21 @@ mod test {
     fn test_invalid_region_header() -> TestResult {
         let line = "### Pangea";
         let parsed = line.parse::<EventLineType>();
-        assert_eq!(parsed, Err(LintError::UnknownRegion("Pangea".to_owned())));
+        assert_eq!(
+            parsed,
+            Err(LintError::UnknownRegion(RegionParseError::new("Pangea")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_header_whitespace() -> TestResult {
+        let line = "### North  America";
+        let parsed = line.parse::<EventLineType>()?;
+        assert_eq!(
+            parsed,
+            EventLineType::EventRegionHeader("North America".to_owned())
+        );
         Ok(())
     }
 
@@ -390,6 +869,93 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_luma_url() -> TestResult {
+        let line = "    * [**Rust NYC Meetup**](https://lu.ma/rust-nyc-oct)";
+        assert!(matches!(
+            line.parse::<EventLineType>()?,
+            EventLineType::EventName(_)
+        ));
+
+        let line = "    * [**Rust NYC Meetup**](https://lu.ma/event/manage/evt-1234)";
+        let parsed = line.parse::<EventLineType>();
+        assert_eq!(
+            parsed,
+            Err(LintError::MalformedPlatformUrl {
+                url: "https://lu.ma/event/manage/evt-1234".to_owned(),
+                expected: "https://lu.ma/<event>",
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_short_event_name() -> TestResult {
+        // only the first label is checked by our line type hint, so a later one can be anything
+        let line = "    * [**November Meetup**](https://www.meetup.com/join-srug/events/304166747/) | [x](https://meet.jit.si/srug)";
+        let parsed = line.parse::<EventLineType>();
+
+        assert_eq!(parsed, Err(LintError::InvalidLinkLabel("x".to_owned())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spaced_bold_event_name() -> TestResult {
+        let line =
+            "    * [** November Meetup **](https://www.meetup.com/join-srug/events/304166747/)";
+        let EventLineType::EventName(events) = line.parse::<EventLineType>()? else {
+            panic!("expected an event name line");
+        };
+
+        let labels: Vec<&str> = events.iter().map(|event| event.label()).collect();
+        assert_eq!(labels, vec!["**November Meetup**"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_name_markup() -> TestResult {
+        let line = "    * [**Intro to `Vec<T>` and `async`**](https://www.meetup.com/join-srug/events/304166747/)";
+        let EventLineType::EventName(events) = line.parse::<EventLineType>()? else {
+            panic!("expected an event name line");
+        };
+        let labels: Vec<&str> = events.iter().map(|event| event.label()).collect();
+        assert_eq!(labels, vec!["**Intro to `Vec<T>` and `async`**"]);
+
+        let line = "    * [**Meetup <script>alert(1)</script>**](https://www.meetup.com/join-srug/events/304166747/)";
+        let parsed = line.parse::<EventLineType>();
+        assert_eq!(
+            parsed,
+            Err(LintError::InvalidLinkLabel(
+                "**Meetup <script>alert(1)</script>**".to_owned()
+            ))
+        );
+
+        let line = "    * [**Meetup <font color=\"red\">Live</font>**](https://www.meetup.com/join-srug/events/304166747/)";
+        assert!(line.parse::<EventLineType>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_name_generics() -> TestResult {
+        // generics outside of backticks are still just text, not html
+        for title in [
+            "**Intro to Vec<T>**",
+            "**HashMap<K, V> Deep Dive**",
+            "**Box<dyn Error> and You**",
+        ] {
+            let line = format!(
+                "    * [{}](https://www.meetup.com/join-srug/events/304166747/)",
+                title
+            );
+            let EventLineType::EventName(events) = line.parse::<EventLineType>()? else {
+                panic!("expected an event name line");
+            };
+            let labels: Vec<&str> = events.iter().map(|event| event.label()).collect();
+            assert_eq!(labels, vec![title]);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_non_bold_event_name() -> TestResult {
         let line = "    * [**November Meetup*](https://www.meetup.com/join-srug/events/304166747/)";
@@ -401,4 +967,437 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_parse_region() {
+        assert_eq!(parse_region("north  america"), Ok("North America"));
+
+        let Err(error) = parse_region("Ocenia") else {
+            panic!("expected an unknown region");
+        };
+        assert_eq!(error.region(), "Ocenia");
+        assert_eq!(error.suggestion(), Some("Oceania"));
+
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert!(error.to_string().contains("did you mean 'Oceania'?"));
+    }
+
+    #[test]
+    fn test_closest_region() {
+        assert_eq!(EventLineType::closest_region("Ocenia"), Some("Oceania"));
+        assert_eq!(
+            EventLineType::closest_region("north america"),
+            Some("North America")
+        );
+        assert_eq!(EventLineType::closest_region("Pangea"), None);
+    }
+
+    #[test]
+    fn test_ignore_directive() -> TestResult {
+        let line = "<!-- twir-lint-ignore EventOutOfOrder EventOutOfDateRange -->";
+        let parsed = line.parse::<EventLineType>()?;
+        let expected = EventLineType::IgnoreDirective(vec![
+            "EventOutOfOrder".to_owned(),
+            "EventOutOfDateRange".to_owned(),
+        ]);
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overview_parse_failed_stage() {
+        let cases = [
+            (
+                "* 2024-10-24 Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                ParseStage::DateDelimiter,
+            ),
+            (
+                "* 2024-10-24 | Virtual [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                ParseStage::LocationDelimiter,
+            ),
+            (
+                "* 2024-10-24 |  | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                ParseStage::Location,
+            ),
+            (
+                "* 2024-10-24 | Virtual | Women in Rust",
+                ParseStage::FirstLink,
+            ),
+        ];
+
+        for (line, stage) in cases {
+            assert_eq!(
+                line.parse::<EventLineType>(),
+                Err(LintError::ParseFailed(stage)),
+                "{}",
+                line
+            );
+        }
+
+        let err = LintError::ParseFailed(ParseStage::LocationDelimiter);
+        assert!(err.to_string().contains("expected ' | ' after location"));
+    }
+
+    #[test]
+    fn test_virtual_time_note() -> TestResult {
+        let line = "* 2024-10-24 | Virtual (18:00 UTC) | [Women in Rust](https://www.meetup.com/women-in-rust/)";
+        let EventLineType::EventDateLocationGroup(overview) = line.parse::<EventLineType>()? else {
+            panic!("expected an event overview line");
+        };
+
+        assert_eq!(overview.date_location().time_note(), Some("18:00 UTC"));
+        assert_eq!(overview.to_markdown(), line);
+
+        let line = "* 2024-10-24 | Virtual (Berlin, DE) | [Rust Berlin](https://www.meetup.com/rust-berlin/)";
+        let EventLineType::EventDateLocationGroup(overview) = line.parse::<EventLineType>()? else {
+            panic!("expected an event overview line");
+        };
+        assert_eq!(overview.date_location().time_note(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_header_casing() -> TestResult {
+        let parsed = "### virtual".parse::<EventLineType>()?;
+        assert_eq!(
+            parsed,
+            EventLineType::EventRegionHeader("Virtual".to_owned())
+        );
+
+        let parsed = "### north america".parse::<EventLineType>()?;
+        assert_eq!(
+            parsed,
+            EventLineType::EventRegionHeader("North America".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_date_sort_date() -> TestResult {
+        let single = "2024-11-06".parse::<EventDate>()?;
+        assert_eq!(single, EventDate::Date("2024-11-06".parse()?));
+        assert_eq!(single.sort_date(), "2024-11-06".parse()?);
+
+        let range = "2024-11-06 - 2024-11-08".parse::<EventDate>()?;
+        assert_eq!(
+            range,
+            EventDate::DateRange("2024-11-06".parse()?, "2024-11-08".parse()?)
+        );
+        assert_eq!(range.sort_date(), "2024-11-06".parse()?);
+        assert_eq!(range.to_string(), "2024-11-06 - 2024-11-08");
+        assert!(range < "2024-11-07".parse::<EventDate>()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_date_months() -> TestResult {
+        assert_eq!("2024-11-06".parse::<EventDate>()?.months(), vec![11]);
+        assert_eq!(
+            "2024-11-30 - 2025-01-02".parse::<EventDate>()?.months(),
+            vec![11, 12, 1]
+        );
+        assert_eq!(
+            "2024-10-31, 2024-11-07".parse::<EventDate>()?.months(),
+            vec![10, 11]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_date_backwards_range() -> TestResult {
+        assert_eq!(
+            "2024-11-08 - 2024-11-06".parse::<EventDate>(),
+            Err(LintError::BackwardsEventDateRange {
+                start: "2024-11-08".parse()?,
+                end: "2024-11-06".parse()?,
+            })
+        );
+
+        let line = "* 2024-11-08 - 2024-11-06 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)";
+        assert_eq!(
+            line.parse::<EventLineType>(),
+            Err(LintError::BackwardsEventDateRange {
+                start: "2024-11-08".parse()?,
+                end: "2024-11-06".parse()?,
+            })
+        );
+
+        // a range over a single day isn't backwards
+        let same_day = "2024-11-06 - 2024-11-06".parse::<EventDate>()?;
+        assert_eq!(same_day.months(), vec![11]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_date_range_ordering() -> TestResult {
+        let single = "2024-11-06".parse::<EventDate>()?;
+        let shorter = "2024-11-06 - 2024-11-07".parse::<EventDate>()?;
+        let longer = "2024-11-06 - 2024-11-08".parse::<EventDate>()?;
+        assert!(single < shorter);
+        assert!(shorter < longer);
+
+        // the end date breaks ties before the location does
+        let shorter = EventDateLocation {
+            date: shorter,
+            location: "Zurich, CH".to_owned(),
+        };
+        let longer = EventDateLocation {
+            date: longer,
+            location: "Amsterdam, NL".to_owned(),
+        };
+        assert!(shorter < longer);
+
+        let mut sorted = vec![longer.clone(), shorter.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![shorter, longer]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_date_multiple() -> TestResult {
+        let multiple = "2024-10-31, 2024-10-24".parse::<EventDate>()?;
+        assert_eq!(
+            multiple,
+            EventDate::Multiple(vec!["2024-10-31".parse()?, "2024-10-24".parse()?])
+        );
+        assert_eq!(multiple.sort_date(), "2024-10-24".parse()?);
+        assert_eq!(multiple.to_string(), "2024-10-31, 2024-10-24");
+
+        let line = "* 2024-10-24, 2024-10-31 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)";
+        let EventLineType::EventDateLocationGroup(overview) = line.parse::<EventLineType>()? else {
+            panic!("expected an event overview line");
+        };
+        assert_eq!(overview.to_markdown(), line);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_delimiter() {
+        let line = "* 2024-10-24 | Virtual (Berlin, DE) | [OpenTechSchool Berlin](https://berline.rs/) | [Rust Berlin](https://www.meetup.com/rust-berlin/)";
+        assert_eq!(
+            line.parse::<EventLineType>(),
+            Err(LintError::WrongDelimiter {
+                line: line.to_owned(),
+                expected: " + ".to_owned(),
+                found: " | ".to_owned(),
+            })
+        );
+
+        let line = "    * [**Rust Hack and Learn**](https://meet.jit.si/RustHackAndLearnBerlin) + [**Mirror: Rust Hack n Learn Meetup**](https://www.meetup.com/rust-berlin/events/298633271/)";
+        assert_eq!(
+            line.parse::<EventLineType>(),
+            Err(LintError::WrongDelimiter {
+                line: line.to_owned(),
+                expected: " | ".to_owned(),
+                found: " + ".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_organizer_label_brackets() -> TestResult {
+        let links = EventLineType::parse_md_link_list(
+            "[Rust [Berlin]](https://www.meetup.com/rust-berlin/) + [C + Rust (Berlin)](https://berline.rs/) + [Rust \\] Fans](https://rust.fans/)",
+            EVENT_DATE_LOCATION_LINK_DELIM,
+        )?;
+        let labels: Vec<&str> = links.iter().map(|link| link.label()).collect();
+        assert_eq!(
+            labels,
+            vec!["Rust [Berlin]", "C + Rust (Berlin)", "Rust \\] Fans"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unterminated_link() {
+        let line = "    * [**Rust Meetup**(https://www.meetup.com/rust-berlin/events/298633271/)";
+        assert_eq!(
+            line.parse::<EventLineType>(),
+            Err(LintError::UnterminatedLink {
+                link: "[**Rust Meetup**(https://www.meetup.com/rust-berlin/events/298633271/)"
+                    .to_owned(),
+                missing: ']',
+            })
+        );
+
+        let line = "    * [**Rust Meetup**](https://www.meetup.com/rust-berlin/events/298633271/";
+        assert_eq!(
+            line.parse::<EventLineType>(),
+            Err(LintError::UnterminatedLink {
+                link: "[**Rust Meetup**](https://www.meetup.com/rust-berlin/events/298633271/"
+                    .to_owned(),
+                missing: ')',
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_md_link_list() -> TestResult {
+        let links = EventLineType::parse_md_link_list(
+            "[**Rust Hack and Learn**](https://meet.jit.si/RustHackAndLearnBerlin) | [**Mirror: Rust Hack n Learn Meetup**](https://www.meetup.com/rust-berlin/events/298633271/) | [**Stream**](https://www.youtube.com/@rustberlin)",
+            EVENT_NAME_LINK_DELIM,
+        )?;
+        let labels: Vec<&str> = links.iter().map(|link| link.label()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "**Rust Hack and Learn**",
+                "**Mirror: Rust Hack n Learn Meetup**",
+                "**Stream**"
+            ]
+        );
+
+        let links = EventLineType::parse_md_link_list(
+            "[OpenTechSchool Berlin](https://berline.rs/) + [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+            EVENT_DATE_LOCATION_LINK_DELIM,
+        )?;
+        let urls: Vec<&str> = links.iter().map(|link| link.url().as_str()).collect();
+        assert_eq!(
+            urls,
+            vec!["https://berline.rs/", "https://www.meetup.com/rust-berlin/"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_url() {
+        for raw in ["rust-atx/", "www.meetup.com/rust-atx/"] {
+            let parsed = format!("* 2024-10-23 | Austin, TX, US | [Rust ATX]({})", raw)
+                .parse::<EventLineType>();
+            assert_eq!(
+                parsed,
+                Err(LintError::RelativeUrl {
+                    raw: raw.to_owned()
+                })
+            );
+        }
+
+        let parsed = try_parse_line("    * [**Rust Lunch**](rust-atx/events/1/)");
+        assert!(parsed.is_err_and(|e| matches!(e.error(), LintError::RelativeUrl { .. })));
+    }
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error;
+
+        let err = try_parse_line(
+            "* 2024-02-30 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+        )
+        .err();
+        assert!(matches!(
+            err.as_ref().map(LineParseError::error),
+            Some(LintError::DateParseError { .. })
+        ));
+        let source = err.as_ref().and_then(|e| e.source());
+        assert!(source.is_some_and(|source| source.is::<ParseError>()));
+
+        let err = try_parse_line("### Atlantis").err();
+        assert!(err
+            .as_ref()
+            .and_then(|e| e.source())
+            .is_some_and(|source| source.is::<RegionParseError>()));
+
+        assert!(LintError::ParseError.source().is_none());
+    }
+
+    #[test]
+    fn test_loose_region_header() -> TestResult {
+        for header in ["## Virtual", "###Virtual", "####  Virtual"] {
+            let parsed = header.parse::<EventLineType>()?;
+            assert_eq!(
+                parsed,
+                EventLineType::EventRegionHeader("Virtual".to_owned())
+            );
+        }
+
+        let parsed = "## Upcoming Events".parse::<EventLineType>()?;
+        assert_eq!(parsed, EventLineType::StartEventSection);
+        Ok(())
+    }
+
+    /// Small xorshift generator so the fuzz test is repeatable without pulling in a dependency
+    struct Xorshift(u64);
+
+    impl Xorshift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn pick<'a>(&mut self, choices: &[&'a str]) -> &'a str {
+            choices[self.next() as usize % choices.len()]
+        }
+    }
+
+    #[test]
+    fn test_try_parse_line_never_panics() {
+        // pieces of real lines plus things likely to trip up slicing, like multibyte characters and stray delimiters
+        const FRAGMENTS: &[&str] = &[
+            "* ",
+            "    * [**",
+            "**",
+            "[",
+            "]",
+            "(",
+            ")",
+            "](",
+            " | ",
+            " + ",
+            ", ",
+            " - ",
+            "|",
+            "#",
+            "### ",
+            "## ",
+            "2024-10-24",
+            "2024-13-45",
+            "9999-99-99",
+            "Virtual",
+            "Online",
+            "Online (",
+            "Europe",
+            "north  america",
+            "https://",
+            "http://",
+            "www.meetup.com/",
+            "lu.ma/",
+            "eventbrite.com/e/",
+            "?eventOrigin=x",
+            "\\",
+            "`",
+            "<b>",
+            "🦀",
+            "Rusty Events between ",
+            "<!-- twir-lint-ignore",
+            "-->",
+            "\u{a0}",
+            "é",
+            " ",
+            "",
+        ];
+
+        let mut rng = Xorshift(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20_000 {
+            let len = rng.next() % 12;
+            let line: String = (0..len).map(|_| rng.pick(FRAGMENTS)).collect();
+            let _ = try_parse_line(&line);
+        }
+    }
+
+    #[test]
+    fn test_try_parse_line() {
+        let parsed = try_parse_line("### Virtual").map(|line| line.line_type());
+        assert_eq!(parsed, Ok("EventRegionHeader(Virtual)".to_owned()));
+
+        let parsed = try_parse_line("### Atlantis");
+        assert!(matches!(
+            parsed.map_err(|e| e.0),
+            Err(LintError::UnknownRegion(_))
+        ));
+
+        // found by the fuzz test, overlapping delimiters used to slice out of order
+        assert!(try_parse_line("    * [**Rust**](https://a.test/) | | ").is_err());
+    }
 }
@@ -0,0 +1,975 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Write},
+};
+
+use chrono::{Datelike, NaiveDate};
+use url::Url;
+
+use crate::{
+    constants::*,
+    event_line_types::{parse_region, EventDate, EventDateLocation},
+    lint::LintError,
+    regex::*,
+};
+
+/// A markdown link, e.g. `[Rust Berlin](https://www.meetup.com/rust-berlin/)`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MarkdownLink {
+    label: String,
+    url: Url,
+}
+
+impl MarkdownLink {
+    pub(crate) fn new(label: String, url: Url) -> Self {
+        Self { label, url }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The url for comparing links, so "meetup.com" and "www.meetup.com" links to the same page are treated as equal.
+    /// We only do this for hosts we know serve the same pages with and without "www."
+    pub fn normalized_url(&self) -> Url {
+        let mut url = self.url.clone();
+        if url.host_str() == Some(MEETUP_BARE_DOMAIN) {
+            // can only fail for urls that can't have a host, which we know this one does
+            let _ = url.set_host(Some(&MEETUP_DOMAIN.to_string()));
+        }
+        url
+    }
+}
+
+impl fmt::Display for MarkdownLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]({})", self.label, self.url)
+    }
+}
+
+/// The groups running an event, from the overview line. Multiple groups are delimited with ' + '
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventGroups(Vec<MarkdownLink>);
+
+impl From<Vec<MarkdownLink>> for EventGroups {
+    fn from(groups: Vec<MarkdownLink>) -> Self {
+        Self(groups)
+    }
+}
+
+impl EventGroups {
+    pub fn iter(&self) -> impl Iterator<Item = &MarkdownLink> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes groups listed more than once (by URL), returning the removed duplicates
+    pub fn dedup(&mut self) -> Vec<MarkdownLink> {
+        let mut seen = Vec::new();
+        let mut duplicates = Vec::new();
+        self.0.retain(|group| {
+            let url = group.normalized_url();
+            if seen.contains(&url) {
+                duplicates.push(group.clone());
+                false
+            } else {
+                seen.push(url);
+                true
+            }
+        });
+        duplicates
+    }
+
+    /// Alphabetizes the groups by name
+    pub fn sort(&mut self) {
+        self.0.sort_by_key(|group| group.label().to_lowercase());
+    }
+}
+
+impl fmt::Display for EventGroups {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let groups: Vec<String> = self.0.iter().map(|group| group.to_string()).collect();
+        write!(f, "{}", groups.join(EVENT_DATE_LOCATION_LINK_DELIM))
+    }
+}
+
+/// Links to the events themselves, from the line under the overview. Multiple links are delimited with ' | '
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Events(Vec<MarkdownLink>);
+
+impl From<Vec<MarkdownLink>> for Events {
+    fn from(events: Vec<MarkdownLink>) -> Self {
+        Self(events)
+    }
+}
+
+impl Events {
+    pub fn iter(&self) -> impl Iterator<Item = &MarkdownLink> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Events {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let events: Vec<String> = self.0.iter().map(|event| event.to_string()).collect();
+        write!(f, "{}", events.join(EVENT_NAME_LINK_DELIM))
+    }
+}
+
+/// The first line of an event listing with the date, location, and groups, e.g.
+/// "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventOverview {
+    date_location: EventDateLocation,
+    groups: EventGroups,
+}
+
+impl EventOverview {
+    pub(crate) fn new(date_location: EventDateLocation, groups: EventGroups) -> Self {
+        Self {
+            date_location,
+            groups,
+        }
+    }
+
+    pub fn date_location(&self) -> &EventDateLocation {
+        &self.date_location
+    }
+
+    pub fn groups(&self) -> &EventGroups {
+        &self.groups
+    }
+
+    /// The overview line as it appears in the draft, with its leading "* "
+    pub fn to_markdown(&self) -> String {
+        format!("* {}", self)
+    }
+}
+
+impl fmt::Display for EventOverview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {}",
+            self.date_location.date(),
+            self.date_location.location(),
+            self.groups
+        )
+    }
+}
+
+/// A full event listing, the overview line and the event link line(s) under it. There's usually a single event line,
+/// but separate events at the same place and date can share one overview
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventListing {
+    overview: EventOverview,
+    events: Vec<Events>,
+}
+
+impl EventListing {
+    pub fn new(overview: EventOverview, events: Events) -> Self {
+        Self {
+            overview,
+            events: vec![events],
+        }
+    }
+
+    pub fn overview(&self) -> &EventOverview {
+        &self.overview
+    }
+
+    /// Each event line under the overview
+    pub fn events(&self) -> &[Events] {
+        &self.events
+    }
+
+    /// Adds another event line under the same overview
+    pub fn add_events(&mut self, events: Events) {
+        self.events.push(events);
+    }
+}
+
+impl From<(EventOverview, Events)> for EventListing {
+    fn from((overview, events): (EventOverview, Events)) -> Self {
+        Self::new(overview, events)
+    }
+}
+
+impl fmt::Display for EventListing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.overview.to_markdown())?;
+        for events in &self.events {
+            write!(f, "\n    * {}", events)?;
+        }
+        Ok(())
+    }
+}
+
+/// All the event listings in the events section, by region
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EventsByRegion(HashMap<String, Vec<EventListing>>);
+
+impl EventsByRegion {
+    /// Adds a listing to the end of the given region
+    pub fn add(&mut self, region: &str, listing: EventListing) {
+        self.0.entry(region.to_owned()).or_default().push(listing);
+    }
+
+    /// The most recently added listing in a region, to add more event lines to
+    pub(crate) fn last_mut(&mut self, region: &str) -> Option<&mut EventListing> {
+        self.0.get_mut(region)?.last_mut()
+    }
+
+    /// Regions and their listings, ordered how they appear in the newsletter
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[EventListing])> {
+        let mut regions: Vec<(&str, &[EventListing])> = self
+            .0
+            .iter()
+            .map(|(region, listings)| (region.as_str(), listings.as_slice()))
+            .collect();
+        regions.sort_by_key(|(region, _)| REGIONS.iter().position(|known| known == region));
+        regions.into_iter()
+    }
+
+    /// The listings for a region, if it has any
+    pub fn get(&self, region: &str) -> Option<&[EventListing]> {
+        self.0.get(region).map(Vec::as_slice)
+    }
+
+    /// The regions with listings, ordered how they appear in the newsletter
+    pub fn regions(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(region, _)| region)
+    }
+
+    /// Number of listings in each region with any, ordered how they appear in the newsletter
+    pub fn counts(&self) -> Vec<(&str, usize)> {
+        self.iter()
+            .map(|(region, listings)| (region, listings.len()))
+            .collect()
+    }
+
+    /// A one line summary of the number of listings, in total and by region, e.g. "3 events (Virtual: 2, Europe: 1)"
+    pub fn count_summary(&self) -> String {
+        let counts = self.counts();
+        let total: usize = counts.iter().map(|(_, count)| count).sum();
+        let regions: Vec<String> = counts
+            .iter()
+            .map(|(region, count)| format!("{}: {}", region, count))
+            .collect();
+        format!("{} events ({})", total, regions.join(", "))
+    }
+
+    /// Only the listings for a single region, e.g. for a regional volunteer reviewing just their section
+    pub fn filter_region(&self, region: &str) -> Result<Self, LintError> {
+        let region = parse_region(region).map_err(LintError::UnknownRegion)?;
+
+        let filtered = self
+            .0
+            .iter()
+            .filter(|(listing_region, _)| *listing_region == region)
+            .map(|(listing_region, listings)| (listing_region.clone(), listings.clone()))
+            .collect();
+
+        Ok(Self(filtered))
+    }
+
+    /// Only the listings taking place on the given day, e.g. to double check events on the first day of the newsletter
+    pub fn on_date(&self, day: NaiveDate) -> Self {
+        let filtered = self
+            .0
+            .iter()
+            .map(|(region, listings)| {
+                let listings: Vec<EventListing> = listings
+                    .iter()
+                    .filter(|listing| listing.overview.date_location().date().includes(day))
+                    .cloned()
+                    .collect();
+                (region.clone(), listings)
+            })
+            .filter(|(_, listings)| !listings.is_empty())
+            .collect();
+
+        Self(filtered)
+    }
+
+    /// Renders the complete events section, from the "## Upcoming Events" header through the closing footer
+    pub fn to_markdown(&self, range: (NaiveDate, NaiveDate)) -> String {
+        format!(
+            "{}\n\n{} {} - {} {}\n\n{}{}\n",
+            START_EVENTS_SECTION,
+            EVENTS_DATE_RANGE_HINT,
+            range.0,
+            range.1,
+            CRAB_EMOJI,
+            self,
+            EVENTS_FOOTER
+        )
+    }
+}
+
+impl EventsByRegion {
+    /// Renders each region's listings grouped under a subheading per ISO week, for a calendar style view
+    pub fn by_week(&self) -> String {
+        let mut output = String::new();
+        for (region, listings) in self.iter() {
+            let mut weeks: BTreeMap<(i32, u32), Vec<&EventListing>> = BTreeMap::new();
+            for listing in listings {
+                let week = listing
+                    .overview
+                    .date_location()
+                    .date()
+                    .sort_date()
+                    .iso_week();
+                weeks
+                    .entry((week.year(), week.week()))
+                    .or_default()
+                    .push(listing);
+            }
+
+            // writing to a string can't fail
+            let _ = writeln!(output, "{}{}", EVENT_REGION_HEADER, region);
+            for ((year, week), listings) in weeks {
+                let _ = writeln!(output, "#### Week {} of {}", week, year);
+                for listing in listings {
+                    let _ = writeln!(output, "{}", listing);
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl EventsByRegion {
+    /// Every listing with its region, in date order across all regions
+    pub fn in_date_order(&self) -> Vec<(&str, &EventListing)> {
+        let mut listings: Vec<(&str, &EventListing)> = self
+            .iter()
+            .flat_map(|(region, listings)| listings.iter().map(move |listing| (region, listing)))
+            .collect();
+        // a stable sort, so events on the same day and place keep the usual region order
+        listings.sort_by(|(_, a), (_, b)| a.overview.cmp(&b.overview));
+        listings
+    }
+
+    /// Renders every listing in a single list in date order, with the region in its own column after the date, for a
+    /// chronological view across regions
+    pub fn by_date(&self) -> String {
+        let mut output = String::new();
+        for (region, listing) in self.in_date_order() {
+            let date_location = listing.overview.date_location();
+            // writing to a string can't fail
+            let _ = write!(
+                output,
+                "* {} | {} | {} | {}",
+                date_location.date(),
+                region,
+                date_location.location(),
+                listing.overview.groups()
+            );
+            for events in listing.events() {
+                let _ = write!(output, "\n    * {}", events);
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl EventsByRegion {
+    /// The earliest and latest day of the listings in each region with any, ordered how they appear in the newsletter
+    pub fn date_spans(&self) -> Vec<(&str, NaiveDate, NaiveDate)> {
+        self.iter()
+            .filter_map(|(region, listings)| {
+                let dates = listings
+                    .iter()
+                    .map(|listing| listing.overview.date_location().date());
+                let first = dates.clone().map(EventDate::sort_date).min()?;
+                let last = dates.flat_map(EventDate::days).max()?;
+                Some((region, first, last))
+            })
+            .collect()
+    }
+
+    /// A line per region with the span of its listings' dates and whether it's within the newsletter's date range, to
+    /// spot a region that only has old events. Like the linter, a multi-day event only needs to start within the range,
+    /// and with `exclusive_end` a listing on the range's end date is outside it
+    pub fn date_span_report(&self, range: (NaiveDate, NaiveDate), exclusive_end: bool) -> String {
+        let in_range = |day: &NaiveDate| {
+            let before_end = if exclusive_end {
+                *day < range.1
+            } else {
+                *day <= range.1
+            };
+            range.0 <= *day && before_end
+        };
+
+        let mut output = String::new();
+        for (region, first, last) in self.date_spans() {
+            let within = if self
+                .get(region)
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|listing| listing.overview.date_location().date().scheduled_days())
+                .all(|day| in_range(&day))
+            {
+                "within the date range"
+            } else {
+                "outside the date range"
+            };
+            // writing to a string can't fail
+            let _ = writeln!(output, "{}: {} - {}, {}", region, first, last, within);
+        }
+        output
+    }
+}
+
+impl EventsByRegion {
+    /// Renders a bordered table with a row per listing, for a quick look over the events in a terminal
+    pub fn to_table(&self) -> String {
+        let header = ["Region", "Date", "Location", "Group", "Title"].map(str::to_owned);
+        let mut rows = vec![header];
+        for (region, listings) in self.iter() {
+            for listing in listings {
+                let date_location = listing.overview.date_location();
+                let groups: Vec<&str> = listing
+                    .overview
+                    .groups()
+                    .iter()
+                    .map(|group| group.label())
+                    .collect();
+                let titles: Vec<&str> = listing
+                    .events
+                    .iter()
+                    .flat_map(|events| events.iter())
+                    .map(|event| event.label().trim_matches('*'))
+                    .collect();
+
+                rows.push([
+                    region.to_owned(),
+                    date_location.date().to_string(),
+                    date_location.location().to_owned(),
+                    groups.join(", "),
+                    titles.join(", "),
+                ]);
+            }
+        }
+
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let border: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+        let border = format!("+{}+\n", border.join("+"));
+
+        let mut output = border.clone();
+        for (i, row) in rows.iter().enumerate() {
+            for (width, cell) in widths.iter().zip(row) {
+                // writing to a string can't fail
+                let _ = write!(output, "| {:<width$} ", cell, width = width);
+            }
+            output.push_str("|\n");
+            // separate the header from the listings
+            if i == 0 {
+                output.push_str(&border);
+            }
+        }
+        output.push_str(&border);
+        output
+    }
+}
+
+impl fmt::Display for EventsByRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (region, listings) in self.iter() {
+            writeln!(f, "{}{}", EVENT_REGION_HEADER, region)?;
+            for listing in listings {
+                writeln!(f, "{}", listing)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The differences between two events sections, e.g. last week's draft and this week's
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EventDiff {
+    added: Vec<EventListing>,
+    removed: Vec<EventListing>,
+    changed: Vec<(EventListing, EventListing)>,
+}
+
+impl EventDiff {
+    pub fn added(&self) -> &[EventListing] {
+        &self.added
+    }
+
+    pub fn removed(&self) -> &[EventListing] {
+        &self.removed
+    }
+
+    /// Listings in both sections with the same event link but different details, as (old, new)
+    pub fn changed(&self) -> &[(EventListing, EventListing)] {
+        &self.changed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for EventDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for listing in &self.added {
+            writeln!(f, "Added:\n{}", listing)?;
+        }
+        for listing in &self.removed {
+            writeln!(f, "Removed:\n{}", listing)?;
+        }
+        for (old, new) in &self.changed {
+            writeln!(f, "Changed:\n{}\nto:\n{}", old, new)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two events sections, matching up listings by their event links
+pub fn diff(old: &EventsByRegion, new: &EventsByRegion) -> EventDiff {
+    let by_url = |events: &EventsByRegion| -> HashMap<Vec<Url>, EventListing> {
+        events
+            .iter()
+            .flat_map(|(_, listings)| listings)
+            .map(|listing| {
+                let urls = listing
+                    .events
+                    .iter()
+                    .flat_map(|events| events.iter())
+                    .map(|event| event.normalized_url());
+                (urls.collect(), listing.clone())
+            })
+            .collect()
+    };
+    let old_listings = by_url(old);
+    let new_listings = by_url(new);
+
+    let mut diff = EventDiff::default();
+    for (url, new_listing) in &new_listings {
+        match old_listings.get(url) {
+            None => diff.added.push(new_listing.clone()),
+            Some(old_listing) if old_listing != new_listing => diff
+                .changed
+                .push((old_listing.clone(), new_listing.clone())),
+            Some(_) => (),
+        }
+    }
+    for (url, old_listing) in &old_listings {
+        if !new_listings.contains_key(url) {
+            diff.removed.push(old_listing.clone());
+        }
+    }
+
+    // hashmap iteration order isn't stable, keep our output in date order
+    diff.added.sort_by(|a, b| a.overview.cmp(&b.overview));
+    diff.removed.sort_by(|a, b| a.overview.cmp(&b.overview));
+    diff.changed.sort_by(|a, b| a.1.overview.cmp(&b.1.overview));
+    diff
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        event_line_types::RegionParseError, lint::EventSectionLinter, test_support::SectionBuilder,
+    };
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    fn link(label: &str, url: &str) -> Result<MarkdownLink, url::ParseError> {
+        Ok(MarkdownLink::new(label.to_owned(), Url::parse(url)?))
+    }
+
+    #[test]
+    fn test_empty_and_single_links() -> TestResult {
+        let groups = EventGroups::from(vec![]);
+        assert!(groups.is_empty());
+        assert_eq!(groups.to_string(), "");
+
+        let events = Events::from(vec![]);
+        assert!(events.is_empty());
+        assert_eq!(events.to_string(), "");
+
+        let groups = EventGroups::from(vec![link(
+            "Rust Berlin",
+            "https://www.meetup.com/rust-berlin/",
+        )?]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(
+            groups.to_string(),
+            "[Rust Berlin](https://www.meetup.com/rust-berlin/)"
+        );
+
+        let events = Events::from(vec![link(
+            "**Rust Hack and Learn**",
+            "https://meet.jit.si/RustHackAndLearnBerlin",
+        )?]);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events.to_string(),
+            "[**Rust Hack and Learn**](https://meet.jit.si/RustHackAndLearnBerlin)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_listing_from_parts() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let (_, listings) = linter.events().iter().next().ok_or("no listings")?;
+        let parsed = &listings[0];
+
+        let listing = EventListing::new(parsed.overview().clone(), parsed.events()[0].clone());
+        assert_eq!(
+            listing.overview().date_location().date(),
+            &EventDate::Date(NaiveDate::from_ymd_opt(2024, 10, 24).ok_or("bad date")?)
+        );
+        assert_eq!(listing.events().len(), 1);
+        assert_eq!(&listing, parsed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_markdown_matches_sample() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+
+        let start = SAMPLE
+            .find(START_EVENTS_SECTION)
+            .ok_or("missing events section")?;
+        let end = SAMPLE.find(EVENTS_FOOTER).ok_or("missing footer")? + EVENTS_FOOTER.len() + 1;
+
+        let range = linter.date_range().ok_or("missing date range")?;
+        assert_eq!(linter.events().to_markdown(range), &SAMPLE[start..end]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_by_week() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let by_week = linter.events().filter_region("Virtual")?.by_week();
+
+        let weeks: Vec<&str> = by_week
+            .lines()
+            .filter(|line| line.starts_with("#### "))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![
+                "#### Week 43 of 2024",
+                "#### Week 44 of 2024",
+                "#### Week 45 of 2024",
+                "#### Week 46 of 2024",
+                "#### Week 47 of 2024",
+            ]
+        );
+
+        // 2024-10-24 is a Thursday in week 43, 2024-10-31 the following Thursday in week 44
+        let week_43 = &by_week[by_week.find("#### Week 43").ok_or("missing week 43")?
+            ..by_week.find("#### Week 44").ok_or("missing week 44")?];
+        assert!(week_43.contains("* 2024-10-24 | Virtual | [Women in Rust]"));
+        assert!(!week_43.contains("* 2024-10-31"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_by_date() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let by_date = linter.events().by_date();
+
+        let overviews: Vec<Vec<&str>> = by_date
+            .lines()
+            .filter(|line| line.starts_with("* "))
+            .map(|line| line.split(" | ").collect())
+            .collect();
+        assert_eq!(overviews.len(), 45);
+
+        let dates: Vec<EventDate> = overviews
+            .iter()
+            .map(|overview| overview[0].trim_start_matches("* ").parse())
+            .collect::<Result<_, _>>()?;
+        assert!(dates.is_sorted());
+
+        // regions are mixed together rather than one after the other
+        let regions: Vec<&str> = overviews.iter().map(|overview| overview[1]).collect();
+        let region_changes = regions.windows(2).filter(|pair| pair[0] != pair[1]).count();
+        assert!(region_changes > REGIONS.len());
+        assert!(by_date.contains(
+            "* 2024-10-23 | North America | Austin, TX, US | [Rust ATX](https://www.meetup.com/rust-atx/)\n    * [**Rust Lunch - Fareground**]"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_span_report() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let range = linter.date_range().ok_or("missing date range")?;
+        let report = linter
+            .events()
+            .filter_region("Virtual")?
+            .date_span_report(range, false);
+        assert_eq!(
+            report,
+            "Virtual: 2024-10-24 - 2024-11-20, within the date range\n"
+        );
+
+        // the last Virtual event is on the end date, so it's out of range when the end is exclusive
+        let report = linter
+            .events()
+            .filter_region("Virtual")?
+            .date_span_report(range, true);
+        assert_eq!(
+            report,
+            "Virtual: 2024-10-24 - 2024-11-20, outside the date range\n"
+        );
+
+        // a multi-day event only needs to start within the range, even though it ends after it
+        let text = SectionBuilder::new()
+            .region("Virtual")
+            .event(
+                "2024-11-20 - 2024-11-22 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "[**Hackathon**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .build();
+        let mut ranged = EventSectionLinter::default();
+        ranged.lint(&text)?;
+        assert_eq!(
+            ranged.events().date_span_report(range, false),
+            "Virtual: 2024-11-20 - 2024-11-22, within the date range\n"
+        );
+        assert_eq!(
+            ranged.events().date_span_report(range, true),
+            "Virtual: 2024-11-20 - 2024-11-22, outside the date range\n"
+        );
+
+        let spans = linter.events().date_spans();
+        assert_eq!(spans.len(), 5);
+        assert_eq!(spans[0].0, "Virtual");
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_table() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let table = linter.events().to_table();
+
+        let listings: usize = linter
+            .events()
+            .iter()
+            .map(|(_, listings)| listings.len())
+            .sum();
+        let rows: Vec<&str> = table.lines().filter(|line| line.starts_with('|')).collect();
+        // plus one for the header
+        assert_eq!(rows.len(), listings + 1);
+        assert!(rows[0].starts_with("| Region "));
+        assert!(rows[1].contains("| 2024-10-24 | Virtual "));
+        assert!(rows[1].contains("| Women in Rust "));
+
+        // every line is padded out to the same width
+        let width = table.lines().next().ok_or("empty table")?.chars().count();
+        assert!(table.lines().all(|line| line.chars().count() == width));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_region() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let events = linter.events();
+
+        let virtual_listings = events.get("Virtual").ok_or("no virtual listings")?;
+        assert_eq!(
+            virtual_listings[0].overview().date_location().location(),
+            "Virtual"
+        );
+        assert_eq!(events.regions().next(), Some("Virtual"));
+        assert_eq!(events.get("Antarctica"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_date() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let (start, _) = linter.date_range().ok_or("no date range")?;
+        let first_day = linter.events().on_date(start);
+
+        let listings: Vec<&EventListing> = first_day
+            .iter()
+            .flat_map(|(_, listings)| listings)
+            .collect();
+        // the sample has a single event on 2024-10-23
+        assert_eq!(listings.len(), 1);
+        assert!(listings
+            .iter()
+            .all(|listing| listing.overview().date_location().date() == &EventDate::Date(start)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_overview_to_markdown() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let (_, listings) = linter.events().iter().next().ok_or("no listings")?;
+
+        assert_eq!(
+            listings[0].overview().to_markdown(),
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_summary() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+
+        // every overview line in the sample is a listing
+        let listings = SAMPLE
+            .lines()
+            .filter(|line| line.starts_with("* 20"))
+            .count();
+        let total: usize = linter
+            .events()
+            .counts()
+            .iter()
+            .map(|(_, count)| count)
+            .sum();
+        assert_eq!(total, listings);
+        assert_eq!(
+            linter.events().count_summary(),
+            format!(
+                "{} events (Virtual: 19, Asia: 1, Europe: 9, North America: 13, Oceania: 3)",
+                listings
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_region() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+
+        let filtered = linter.events().filter_region("Virtual")?;
+        let regions: Vec<(&str, usize)> = filtered
+            .iter()
+            .map(|(region, listings)| (region, listings.len()))
+            .collect();
+        assert_eq!(regions, vec![("Virtual", 19)]);
+
+        assert_eq!(
+            linter.events().filter_region("Pangea"),
+            Err(LintError::UnknownRegion(RegionParseError::new("Pangea")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalized_url() -> TestResult {
+        let bare = link("Rust Berlin", "https://meetup.com/rust-berlin/events/1/")?;
+        let www = link(
+            "Rust Berlin",
+            "https://www.meetup.com/rust-berlin/events/1/",
+        )?;
+        assert_eq!(bare.normalized_url(), www.normalized_url());
+
+        let other = link("Rust Berlin", "https://berline.rs/")?;
+        assert_eq!(other.normalized_url(), *other.url());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_ignores_www() -> TestResult {
+        let bare = SAMPLE.replace(
+            "https://www.meetup.com/dallasrust/events/301585671/",
+            "https://meetup.com/dallasrust/events/301585671/",
+        );
+
+        let mut old = EventSectionLinter::default();
+        old.lint(SAMPLE)?;
+        let mut new = EventSectionLinter::default();
+        new.lint(&bare)?;
+
+        let diff = diff(old.events(), new.events());
+        assert!(diff.added().is_empty());
+        assert!(diff.removed().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> TestResult {
+        let removed = concat!(
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+            "    * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)\n",
+        );
+        let added = concat!(
+            "* 2024-11-10 | Virtual (Dallas, TX, US) | [Dallas Rust](https://www.meetup.com/dallasrust/)\n",
+            "    * [**Second Sunday**](https://www.meetup.com/dallasrust/events/299346999/)\n",
+        );
+        let updated = SAMPLE
+            .replace(removed, "")
+            .replace(
+                "* 2024-11-12 | Virtual (Dallas",
+                &format!("{}* 2024-11-12 | Virtual (Dallas", added),
+            )
+            .replace("[**Last Tuesday**]", "[**Last Tuesday Hangout**]");
+
+        let mut old = EventSectionLinter::default();
+        old.lint(SAMPLE)?;
+        let mut new = EventSectionLinter::default();
+        new.lint(&updated)?;
+
+        let diff = diff(old.events(), new.events());
+        assert_eq!(diff.added().len(), 1);
+        assert_eq!(format!("{}\n", diff.added()[0]), added);
+        assert_eq!(diff.removed().len(), 1);
+        assert_eq!(format!("{}\n", diff.removed()[0]), removed);
+        assert_eq!(diff.changed().len(), 1);
+        assert!(diff.changed()[0]
+            .1
+            .to_string()
+            .contains("Last Tuesday Hangout"));
+
+        assert!(diff.to_string().starts_with("Added:\n* 2024-11-10"));
+        assert!(super::diff(old.events(), old.events()).is_empty());
+        Ok(())
+    }
+}
@@ -1,5 +1,13 @@
 pub mod args;
 pub mod constants;
+pub mod diff;
+pub mod draft;
 pub mod event_line_types;
+pub mod events;
 pub mod lint;
+pub mod lsp;
+pub mod reader;
 pub mod regex;
+pub mod rss;
+#[cfg(test)]
+pub(crate) mod test_support;
@@ -1,12 +1,22 @@
-use std::fmt;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
-use chrono::{NaiveDate, ParseError};
+use chrono::{Datelike, Month, NaiveDate, ParseError};
 use log::{debug, error, info, warn};
 use url::Url;
 
 use crate::{
     constants::*,
-    event_line_types::{EventDateLocation, EventLineType},
+    event_line_types::{
+        normalize_apostrophes, normalize_whitespace, EventDate, EventDateLocation, EventLineType,
+        ParseStage, RegionParseError,
+    },
+    events::{EventOverview, Events, EventsByRegion, MarkdownLink},
+    reader::Reader,
+    regex::{EVENT_DATE_LOCATION_SPACING_RE, MEETUP_EVENT_PATH_RE},
 };
 
 // TODO:
@@ -17,6 +27,66 @@ use crate::{
 // - check for duplicated links
 // - make sure each location in virtual section starts with "virtual"
 
+/// How serious a lint is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Stable categories of lints, for tools that need an id that won't change as lints are added or their messages
+/// reworded, e.g. rule ids in machine readable output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintErrorKind {
+    /// The section's lines aren't in the expected order, or something's missing
+    Structure,
+    /// A line couldn't be parsed
+    Parse,
+    /// Events within a region aren't in date order
+    OutOfOrder,
+    /// A date outside of, or suspicious for, the newsletter's date range
+    OutOfRange,
+    /// A link that's malformed or points somewhere it shouldn't
+    BadLink,
+    /// The same link listed more than once where it shouldn't be
+    DuplicateLink,
+    /// The same event listed more than once
+    DuplicateEvent,
+    /// A region header that's unknown or not written as expected
+    Region,
+    /// Group or event names that don't match up
+    Naming,
+    /// Whitespace, casing, and other formatting that doesn't affect meaning
+    Formatting,
+    /// The lint as a whole failed
+    Failed,
+}
+
+impl LintErrorKind {
+    /// The kind's id, e.g. "out-of-order"
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Structure => "structure",
+            Self::Parse => "parse",
+            Self::OutOfOrder => "out-of-order",
+            Self::OutOfRange => "out-of-range",
+            Self::BadLink => "bad-link",
+            Self::DuplicateLink => "duplicate-link",
+            Self::DuplicateEvent => "duplicate-event",
+            Self::Region => "region",
+            Self::Naming => "naming",
+            Self::Formatting => "formatting",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for LintErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
 /// An error linting - this error should provide enough information by itself to be useful to a user (one would hope)
 // TODO: probably split this into linter logic errors (like invalid state transitions) and parsing/validation errors
 #[derive(Debug, PartialEq, Eq)]
@@ -56,11 +126,371 @@ pub enum LintError {
     /// An invalid url in our events
     InvalidUrl(url::ParseError),
     /// A region header (Virtual, Europe, etc) we do not recognize
-    UnknownRegion(String),
+    UnknownRegion(RegionParseError),
     /// URL contains a tracker that we want to strip out
     UrlContainsTracker(Url),
     /// Invalid format for a link label, e.g. [link label](https://mylink.test)
     InvalidLinkLabel(String),
+    /// A descriptive note directly under a region header, before the region's first event
+    UnexpectedRegionNote,
+    /// The footer should be separated from the last region by exactly one blank line
+    FooterSpacing {
+        blank_lines: usize,
+    },
+    /// The same group URL is used with different group names
+    InconsistentGroupName {
+        url: String,
+        name: String,
+        previous_name: String,
+    },
+    /// A link that doesn't use https
+    NonHttpsUrl(String),
+    /// The date range line doesn't end with the crab emoji
+    MissingCrabEmoji,
+    /// A link to a host that isn't in our allowlist
+    DisallowedHost(String),
+    /// Extra spaces around the pipes in an event's overview line
+    InconsistentSpacing,
+    /// An event's title is the same as its group's name
+    TitleEqualsGroupName(String),
+    /// A line looked like an event overview but we couldn't parse it, with the part that failed
+    ParseFailed(ParseStage),
+    /// A location using a synonym, e.g. "Online" rather than "Virtual"
+    NonCanonicalLocation {
+        found: String,
+        expected: String,
+    },
+    /// The newsletter's date range is unusually long or backwards, likely a typo in one of the dates
+    SuspiciousDateRange {
+        start: NaiveDate,
+        end: NaiveDate,
+    },
+    /// A region header with the wrong casing, e.g. "### virtual"
+    RegionCasing {
+        found: String,
+        expected: String,
+    },
+    /// An event has a different number of organizers than event links, which may be fine but is worth a look
+    OrganizerLinkCountMismatch {
+        organizers: usize,
+        links: usize,
+    },
+    /// An organizer link goes to a specific event rather than the group's page
+    OrganizerUrlTooSpecific(String),
+    /// An event title we've already seen in the same region, maybe pasted onto the wrong event
+    DuplicateTitle {
+        title: String,
+        previous_line: usize,
+    },
+    /// An event date far after the newsletter's date range, probably a typo in the year
+    ImplausibleFutureDate {
+        event_date: NaiveDate,
+    },
+    /// The same organizer listed more than once for an event
+    DuplicateOrganizer(String),
+    /// The Virtual region comes after another region, it should always be first
+    VirtualNotFirst,
+    /// The events section doesn't list any events
+    NoEventsFound,
+    /// A region header without exactly "### " before the region name
+    RegionHeaderFormatting(String),
+    /// A line with non-breaking spaces, which we treat as regular spaces
+    NonBreakingSpace,
+    /// An event name line indented with something other than four spaces
+    DetailIndentation {
+        line: usize,
+        spaces: usize,
+    },
+    /// An event overview before the first region header
+    EventBeforeRegion {
+        line: usize,
+    },
+    /// A link to an event hosting platform that doesn't look like one of its event or organizer pages
+    MalformedPlatformUrl {
+        url: String,
+        expected: &'static str,
+    },
+    /// Text after the crab emoji at the end of the date range line
+    DateRangeTrailingText {
+        line: usize,
+        trailing: String,
+    },
+    /// A region header with extra whitespace in or around the region name, e.g. "### North  America"
+    RegionHeaderWhitespace {
+        line: usize,
+    },
+    /// The same event link listed more than once in the events section, with every line it's on
+    DuplicateEventUrl {
+        url: String,
+        lines: Vec<usize>,
+    },
+    /// Links joined with the wrong delimiter, e.g. organizers joined with " | " rather than " + "
+    WrongDelimiter {
+        line: String,
+        expected: String,
+        found: String,
+    },
+    /// A second date range line in the events section
+    DuplicateDateRange {
+        line: usize,
+    },
+    /// A markdown link missing its closing bracket or parenthesis, e.g. "[**Title**(https://...)"
+    UnterminatedLink {
+        link: String,
+        missing: char,
+    },
+    /// An event link that's the same as its organizer's link, usually the group's link pasted in place of the event's
+    OrganizerEqualsEventUrl {
+        line: usize,
+    },
+    /// The events section header with different casing, e.g. "## Upcoming events"
+    HeaderCasing {
+        line: usize,
+    },
+    /// An event link with no path, just a domain like "https://www.meetup.com/"
+    BareDomainUrl {
+        line: usize,
+        url: String,
+    },
+    /// A group name with a curly or other lookalike apostrophe rather than a plain "'"
+    NonAsciiApostrophe {
+        line: usize,
+    },
+    /// A line that could be the rest of a wrapped event title, after an event line that already looks complete
+    AmbiguousContinuation {
+        line: usize,
+    },
+    /// A link without a scheme and host, e.g. "(rust-atx/)" or "(www.meetup.com/rust-atx/)"
+    RelativeUrl {
+        raw: String,
+    },
+    /// An event with the same group and title as an earlier one, maybe a recurring event listed more than once
+    PossibleRecurringDuplicate {
+        line: usize,
+        previous_line: usize,
+    },
+    /// An event title naming a month that none of the event's dates are in, e.g. "November Meetup" on 2024-10-24
+    TitleMonthMismatch {
+        line: usize,
+        month: String,
+    },
+    /// An event overview without any groups, which the parser shouldn't let through
+    NoOrganizers {
+        line: usize,
+    },
+    /// An invisible zero-width character, e.g. a zero-width space pasted into a title or link
+    ZeroWidthCharacter {
+        line: usize,
+    },
+    /// The date range line before the "## Upcoming Events" header rather than after it
+    DateRangeBeforeSection {
+        line: usize,
+    },
+    /// "http://" links on the line were rewritten to "https://" while editing
+    UpgradedHttpLinks {
+        line: usize,
+    },
+    /// An event's date range that ends before it starts, e.g. "2024-11-08 - 2024-11-06"
+    BackwardsEventDateRange {
+        start: NaiveDate,
+        end: NaiveDate,
+    },
+}
+
+impl LintError {
+    /// Name of the lint, this is what's used to refer to a lint in ignore directives
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::InvalidStateChange { .. } => "InvalidStateChange",
+            Self::UnexpectedDateRange => "UnexpectedDateRange",
+            Self::UnexpectedLineType { .. } => "UnexpectedLineType",
+            Self::EventOutOfDateRange { .. } => "EventOutOfDateRange",
+            Self::EventOutOfOrder { .. } => "EventOutOfOrder",
+            Self::DateRangeNotSet => "DateRangeNotSet",
+            Self::RegexError { .. } => "RegexError",
+            Self::DateParseError { .. } => "DateParseError",
+            Self::ParseError => "ParseError",
+            Self::UnexpectedEnd => "UnexpectedEnd",
+            Self::LintFailed => "LintFailed",
+            Self::InvalidUrl(_) => "InvalidUrl",
+            Self::UnknownRegion(_) => "UnknownRegion",
+            Self::UrlContainsTracker(_) => "UrlContainsTracker",
+            Self::InvalidLinkLabel(_) => "InvalidLinkLabel",
+            Self::UnexpectedRegionNote => "UnexpectedRegionNote",
+            Self::FooterSpacing { .. } => "FooterSpacing",
+            Self::InconsistentGroupName { .. } => "InconsistentGroupName",
+            Self::NonHttpsUrl(_) => "NonHttpsUrl",
+            Self::MissingCrabEmoji => "MissingCrabEmoji",
+            Self::DisallowedHost(_) => "DisallowedHost",
+            Self::InconsistentSpacing => "InconsistentSpacing",
+            Self::TitleEqualsGroupName(_) => "TitleEqualsGroupName",
+            Self::ParseFailed(_) => "ParseFailed",
+            Self::NonCanonicalLocation { .. } => "NonCanonicalLocation",
+            Self::SuspiciousDateRange { .. } => "SuspiciousDateRange",
+            Self::RegionCasing { .. } => "RegionCasing",
+            Self::OrganizerLinkCountMismatch { .. } => "OrganizerLinkCountMismatch",
+            Self::OrganizerUrlTooSpecific(_) => "OrganizerUrlTooSpecific",
+            Self::DuplicateTitle { .. } => "DuplicateTitle",
+            Self::ImplausibleFutureDate { .. } => "ImplausibleFutureDate",
+            Self::DuplicateOrganizer(_) => "DuplicateOrganizer",
+            Self::VirtualNotFirst => "VirtualNotFirst",
+            Self::NoEventsFound => "NoEventsFound",
+            Self::RegionHeaderFormatting(_) => "RegionHeaderFormatting",
+            Self::NonBreakingSpace => "NonBreakingSpace",
+            Self::DetailIndentation { .. } => "DetailIndentation",
+            Self::EventBeforeRegion { .. } => "EventBeforeRegion",
+            Self::MalformedPlatformUrl { .. } => "MalformedPlatformUrl",
+            Self::DateRangeTrailingText { .. } => "DateRangeTrailingText",
+            Self::RegionHeaderWhitespace { .. } => "RegionHeaderWhitespace",
+            Self::DuplicateEventUrl { .. } => "DuplicateEventUrl",
+            Self::WrongDelimiter { .. } => "WrongDelimiter",
+            Self::DuplicateDateRange { .. } => "DuplicateDateRange",
+            Self::UnterminatedLink { .. } => "UnterminatedLink",
+            Self::OrganizerEqualsEventUrl { .. } => "OrganizerEqualsEventUrl",
+            Self::HeaderCasing { .. } => "HeaderCasing",
+            Self::BareDomainUrl { .. } => "BareDomainUrl",
+            Self::NonAsciiApostrophe { .. } => "NonAsciiApostrophe",
+            Self::AmbiguousContinuation { .. } => "AmbiguousContinuation",
+            Self::RelativeUrl { .. } => "RelativeUrl",
+            Self::PossibleRecurringDuplicate { .. } => "PossibleRecurringDuplicate",
+            Self::TitleMonthMismatch { .. } => "TitleMonthMismatch",
+            Self::NoOrganizers { .. } => "NoOrganizers",
+            Self::ZeroWidthCharacter { .. } => "ZeroWidthCharacter",
+            Self::DateRangeBeforeSection { .. } => "DateRangeBeforeSection",
+            Self::UpgradedHttpLinks { .. } => "UpgradedHttpLinks",
+            Self::BackwardsEventDateRange { .. } => "BackwardsEventDateRange",
+        }
+    }
+
+    /// How serious the lint is. Warnings are reported but don't fail the lint, unless warnings are denied. A few lints
+    /// that are usually warnings can be made errors with an option, e.g. `NoEventsFound` with `require_events`
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::UnexpectedRegionNote
+            | Self::FooterSpacing { .. }
+            | Self::NonHttpsUrl(_)
+            | Self::MissingCrabEmoji
+            | Self::InconsistentSpacing
+            | Self::TitleEqualsGroupName(_)
+            | Self::NonCanonicalLocation { .. }
+            | Self::SuspiciousDateRange { .. }
+            | Self::RegionCasing { .. }
+            | Self::OrganizerLinkCountMismatch { .. }
+            | Self::DuplicateTitle { .. }
+            | Self::ImplausibleFutureDate { .. }
+            | Self::DuplicateOrganizer(_)
+            | Self::VirtualNotFirst
+            | Self::NoEventsFound
+            | Self::RegionHeaderFormatting(_)
+            | Self::NonBreakingSpace
+            | Self::DetailIndentation { .. }
+            | Self::DateRangeTrailingText { .. }
+            | Self::RegionHeaderWhitespace { .. }
+            | Self::DuplicateEventUrl { .. }
+            | Self::OrganizerEqualsEventUrl { .. }
+            | Self::HeaderCasing { .. }
+            | Self::NonAsciiApostrophe { .. }
+            | Self::PossibleRecurringDuplicate { .. }
+            | Self::TitleMonthMismatch { .. }
+            | Self::ZeroWidthCharacter { .. }
+            | Self::UpgradedHttpLinks { .. } => Severity::Warning,
+            Self::InvalidStateChange { .. }
+            | Self::UnexpectedDateRange
+            | Self::UnexpectedLineType { .. }
+            | Self::EventOutOfDateRange { .. }
+            | Self::EventOutOfOrder { .. }
+            | Self::DateRangeNotSet
+            | Self::RegexError { .. }
+            | Self::DateParseError { .. }
+            | Self::ParseError
+            | Self::UnexpectedEnd
+            | Self::LintFailed
+            | Self::InvalidUrl(_)
+            | Self::UnknownRegion(_)
+            | Self::UrlContainsTracker(_)
+            | Self::InvalidLinkLabel(_)
+            | Self::InconsistentGroupName { .. }
+            | Self::DisallowedHost(_)
+            | Self::ParseFailed(_)
+            | Self::OrganizerUrlTooSpecific(_)
+            | Self::EventBeforeRegion { .. }
+            | Self::MalformedPlatformUrl { .. }
+            | Self::WrongDelimiter { .. }
+            | Self::DuplicateDateRange { .. }
+            | Self::UnterminatedLink { .. }
+            | Self::BareDomainUrl { .. }
+            | Self::AmbiguousContinuation { .. }
+            | Self::RelativeUrl { .. }
+            | Self::NoOrganizers { .. }
+            | Self::DateRangeBeforeSection { .. }
+            | Self::BackwardsEventDateRange { .. } => Severity::Error,
+        }
+    }
+
+    /// The lint's stable category, see `LintErrorKind`
+    pub fn kind(&self) -> LintErrorKind {
+        match self {
+            Self::InvalidStateChange { .. }
+            | Self::UnexpectedDateRange
+            | Self::UnexpectedLineType { .. }
+            | Self::DateRangeNotSet
+            | Self::UnexpectedEnd
+            | Self::UnexpectedRegionNote
+            | Self::EventBeforeRegion { .. }
+            | Self::DuplicateDateRange { .. }
+            | Self::AmbiguousContinuation { .. }
+            | Self::NoEventsFound
+            | Self::VirtualNotFirst
+            | Self::NoOrganizers { .. }
+            | Self::DateRangeBeforeSection { .. } => LintErrorKind::Structure,
+            Self::RegexError { .. }
+            | Self::DateParseError { .. }
+            | Self::ParseError
+            | Self::ParseFailed(_)
+            | Self::InvalidLinkLabel(_)
+            | Self::WrongDelimiter { .. }
+            | Self::UnterminatedLink { .. }
+            | Self::BackwardsEventDateRange { .. } => LintErrorKind::Parse,
+            Self::EventOutOfOrder { .. } => LintErrorKind::OutOfOrder,
+            Self::EventOutOfDateRange { .. }
+            | Self::ImplausibleFutureDate { .. }
+            | Self::SuspiciousDateRange { .. }
+            | Self::TitleMonthMismatch { .. } => LintErrorKind::OutOfRange,
+            Self::InvalidUrl(_)
+            | Self::UrlContainsTracker(_)
+            | Self::NonHttpsUrl(_)
+            | Self::UpgradedHttpLinks { .. }
+            | Self::DisallowedHost(_)
+            | Self::MalformedPlatformUrl { .. }
+            | Self::BareDomainUrl { .. }
+            | Self::RelativeUrl { .. }
+            | Self::OrganizerUrlTooSpecific(_) => LintErrorKind::BadLink,
+            Self::DuplicateEventUrl { .. }
+            | Self::OrganizerEqualsEventUrl { .. }
+            | Self::DuplicateOrganizer(_) => LintErrorKind::DuplicateLink,
+            Self::DuplicateTitle { .. } | Self::PossibleRecurringDuplicate { .. } => {
+                LintErrorKind::DuplicateEvent
+            }
+            Self::UnknownRegion(_)
+            | Self::RegionCasing { .. }
+            | Self::RegionHeaderFormatting(_)
+            | Self::RegionHeaderWhitespace { .. } => LintErrorKind::Region,
+            Self::InconsistentGroupName { .. }
+            | Self::TitleEqualsGroupName(_)
+            | Self::NonAsciiApostrophe { .. }
+            | Self::OrganizerLinkCountMismatch { .. } => LintErrorKind::Naming,
+            Self::FooterSpacing { .. }
+            | Self::MissingCrabEmoji
+            | Self::InconsistentSpacing
+            | Self::NonCanonicalLocation { .. }
+            | Self::NonBreakingSpace
+            | Self::DetailIndentation { .. }
+            | Self::DateRangeTrailingText { .. }
+            | Self::HeaderCasing { .. }
+            | Self::ZeroWidthCharacter { .. } => LintErrorKind::Formatting,
+            Self::LintFailed => LintErrorKind::Failed,
+        }
+    }
 }
 
 impl fmt::Display for LintError {
@@ -117,19 +547,260 @@ impl fmt::Display for LintError {
             Self::UnexpectedEnd => "Reached unexpected end of file".to_owned(),
             Self::LintFailed => "Lint failed! See above for error details".to_owned(),
             Self::InvalidUrl(e) => format!("URL parsing error: '{}'", e),
-            Self::UnknownRegion(region) => format!(
-                "Found unknown region: '{}'\nExpected one of '{:?}'",
-                region, REGIONS
-            ),
+            Self::UnknownRegion(e) => e.to_string(),
             Self::UrlContainsTracker(url) => format!("URL '{}' contains a tracker", url),
             Self::InvalidLinkLabel(label) => format!("Link label '{}' is invalid", label),
+            Self::UnexpectedRegionNote => {
+                "Found a note under a region header, expected the region's first event".to_owned()
+            }
+            Self::FooterSpacing { blank_lines } => format!(
+                "Expected exactly one blank line before the events section footer, found {}",
+                blank_lines
+            ),
+            Self::InconsistentGroupName {
+                url,
+                name,
+                previous_name,
+            } => format!(
+                "Group URL '{}' has name '{}' but was previously named '{}'",
+                url, name, previous_name
+            ),
+            Self::NonHttpsUrl(url) => format!("URL '{}' should use https", url),
+            Self::MissingCrabEmoji => {
+                format!("Expected the date range line to end with '{}'", CRAB_EMOJI)
+            }
+            Self::DisallowedHost(host) => format!("Host '{}' is not in the allowed hosts", host),
+            Self::InconsistentSpacing => {
+                "Expected exactly one space on either side of each '|'".to_owned()
+            }
+            Self::TitleEqualsGroupName(title) => {
+                format!(
+                    "Event title '{}' is the same as its group name, is this the right link label?",
+                    title
+                )
+            }
+            Self::ParseFailed(stage) => format!("Failed to parse line, {}", stage),
+            Self::NonCanonicalLocation { found, expected } => {
+                format!("Location '{}' should be written as '{}'", found, expected)
+            }
+            Self::SuspiciousDateRange { start, end } => {
+                format!(
+                    "Date range {} - {} looks wrong, is there a typo in one of the dates?",
+                    start, end
+                )
+            }
+            Self::RegionCasing { found, expected } => {
+                format!("Region '{}' should be written as '{}'", found, expected)
+            }
+            Self::OrganizerLinkCountMismatch { organizers, links } => {
+                format!(
+                    "Event has {} organizer(s) but {} event link(s), is anything missing?",
+                    organizers, links
+                )
+            }
+            Self::OrganizerUrlTooSpecific(url) => {
+                format!(
+                    "Organizer link '{}' should go to the group's page, not a specific event",
+                    url
+                )
+            }
+            Self::DuplicateTitle {
+                title,
+                previous_line,
+            } => {
+                format!(
+                    "Event title '{}' was already used on line #{}",
+                    title, previous_line
+                )
+            }
+            Self::ImplausibleFutureDate { event_date } => {
+                format!(
+                    "Event date '{}' is far in the future, is the year right?",
+                    event_date
+                )
+            }
+            Self::DuplicateOrganizer(organizer) => {
+                format!("Organizer '{}' is listed more than once", organizer)
+            }
+            Self::VirtualNotFirst => "Virtual events should be the first region".to_owned(),
+            Self::NoEventsFound => "No events found in the events section".to_owned(),
+            Self::RegionHeaderFormatting(header) => {
+                format!(
+                    "Region header '{}' should start with exactly '{}'",
+                    header, EVENT_REGION_HEADER
+                )
+            }
+            Self::NonBreakingSpace => {
+                "Line contains non-breaking spaces, these should be regular spaces".to_owned()
+            }
+            Self::DetailIndentation { line, spaces } => {
+                format!(
+                    "Event name on line #{} is indented with {} spaces, expected {}",
+                    line,
+                    spaces,
+                    EVENT_NAME_INDENT.len()
+                )
+            }
+            Self::EventBeforeRegion { line } => {
+                format!(
+                    "Event on line #{} comes before any region header, events must follow a '{}<Region>' header",
+                    line, EVENT_REGION_HEADER
+                )
+            }
+            Self::MalformedPlatformUrl { url, expected } => {
+                format!("URL '{}' doesn't look like '{}'", url, expected)
+            }
+            Self::DateRangeTrailingText { line, trailing } => {
+                format!(
+                    "Date range on line #{} has '{}' after the {}, it should end with it",
+                    line, trailing, CRAB_EMOJI
+                )
+            }
+            Self::RegionHeaderWhitespace { line } => {
+                format!(
+                    "Region header on line #{} should have single spaces between words",
+                    line
+                )
+            }
+            Self::DuplicateEventUrl { url, lines } => {
+                let lines: Vec<String> = lines.iter().map(|line| format!("#{}", line)).collect();
+                format!(
+                    "Event link '{}' is listed more than once, on lines {}",
+                    url,
+                    lines.join(", ")
+                )
+            }
+            Self::WrongDelimiter {
+                line,
+                expected,
+                found,
+            } => {
+                format!(
+                    "Links in '{}' should be joined with '{}', found '{}'",
+                    line, expected, found
+                )
+            }
+            Self::DuplicateDateRange { line } => {
+                format!(
+                    "Date range on line #{} is a duplicate, the events section should only have one",
+                    line
+                )
+            }
+            Self::UnterminatedLink { link, missing } => {
+                format!("Link '{}' is missing a closing '{}'", link, missing)
+            }
+            Self::OrganizerEqualsEventUrl { line } => {
+                format!(
+                    "Event link on line #{} is the same as its organizer's link, it should link to the event itself",
+                    line
+                )
+            }
+            Self::HeaderCasing { line } => {
+                format!(
+                    "Events section header on line #{} should be exactly '{}'",
+                    line, START_EVENTS_SECTION
+                )
+            }
+            Self::BareDomainUrl { line, url } => {
+                format!(
+                    "Event link '{}' on line #{} is just a domain, it should link to the event itself",
+                    url, line
+                )
+            }
+            Self::NonAsciiApostrophe { line } => {
+                format!(
+                    "Group name on line #{} has a non-ASCII apostrophe, use a plain \"'\" instead",
+                    line
+                )
+            }
+            Self::AmbiguousContinuation { line } => {
+                format!(
+                    "Line #{} could be the rest of the event title above it, but that line already looks complete. Join them into one line if it's a wrapped title, or remove it",
+                    line
+                )
+            }
+            Self::RelativeUrl { raw } => {
+                format!(
+                    "Link '{}' isn't a full URL, use an absolute https link like 'https://www.meetup.com/rust-atx/'",
+                    raw
+                )
+            }
+            Self::PossibleRecurringDuplicate {
+                line,
+                previous_line,
+            } => {
+                format!(
+                    "Event on line #{} has the same group and title as the event on line #{}, is it a recurring event listed twice?",
+                    line, previous_line
+                )
+            }
+            Self::TitleMonthMismatch { line, month } => {
+                format!(
+                    "Event title on line #{} mentions {}, but the event isn't in {}, is the date right?",
+                    line, month, month
+                )
+            }
+            Self::NoOrganizers { line } => {
+                format!("Event on line #{} doesn't list any groups", line)
+            }
+            Self::ZeroWidthCharacter { line } => {
+                format!(
+                    "Line #{} has invisible zero-width characters, remove them",
+                    line
+                )
+            }
+            Self::DateRangeBeforeSection { line } => {
+                format!(
+                    "Date range on line #{} is before the '{}' header, it should come right after it",
+                    line, START_EVENTS_SECTION
+                )
+            }
+            Self::UpgradedHttpLinks { line } => {
+                format!("Rewrote the http links on line #{} to https", line)
+            }
+            Self::BackwardsEventDateRange { start, end } => {
+                format!("Event date range {} - {} ends before it starts", start, end)
+            }
         };
 
         write!(f, "{}", error_msg)
     }
 }
 
-impl std::error::Error for LintError {}
+impl std::error::Error for LintError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::DateParseError { chrono_error } => Some(chrono_error),
+            Self::InvalidUrl(e) => Some(e),
+            Self::UnknownRegion(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A lint error along with the (1-indexed) line number in the file it was found on
+#[derive(Debug, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    line_num: usize,
+    error: LintError,
+    /// Whether this was reported as an error or a warning, which can differ from the lint's own severity with options
+    /// like `deny_warnings`
+    severity: Severity,
+}
+
+impl LintDiagnostic {
+    pub fn line_num(&self) -> usize {
+        self.line_num
+    }
+
+    pub fn error(&self) -> &LintError {
+        &self.error
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+}
 
 /// Overall state of the linter, keeps track of what "section" we are in
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -200,6 +871,105 @@ impl fmt::Display for LinterState {
     }
 }
 
+/// Options for what the linter checks and how it edits the draft
+#[derive(Clone, Debug)]
+pub struct LinterConfig {
+    /// Whether we should make edits or not, if enabled we will save each (potentially edited) line
+    pub should_edit: bool,
+    /// Maximum error count before bailing, 0 for no limit
+    pub error_limit: u32,
+    /// Rewrite "http://" links in the events section to "https://" when editing, with a warning for each line we
+    /// rewrite rather than one for each link
+    pub upgrade_http: bool,
+    /// Alphabetize the groups in each event's overview line
+    pub sort_organizers: bool,
+    /// Put the listings in each region in date order when editing, rather than reporting events out of order. This
+    /// only moves whole listings, the groups in each overview line stay as written unless `sort_organizers` is set too
+    pub sort_events: bool,
+    /// Treat the end of the newsletter's date range as exclusive, so events on the end date are out of range
+    pub range_exclusive_end: bool,
+    /// Only check the section's structure, so the date range line is optional and event dates aren't checked against it
+    pub structure_only: bool,
+    /// After an error, skip ahead to the next region header (or the footer) rather than trying to continue from the
+    /// next line, which avoids a cascade of errors when a region is badly garbled
+    pub resync_on_region: bool,
+    /// Fail rather than warn if the events section has no events at all
+    pub require_events: bool,
+    /// Warn if the newsletter's date range spans more than this many days, usually a typo in one of the dates
+    pub max_range_days: u32,
+    /// Warn about events more than this many days after the newsletter's date range, usually a typo in the year
+    pub max_days_after_range: u32,
+    /// If set, only links to these hosts (or their subdomains) are allowed
+    pub allowed_hosts: Option<Vec<String>>,
+    /// Treat warnings as errors, so they count towards the error limit and fail the lint
+    pub deny_warnings: bool,
+    /// Warn about events with the same group and title as an earlier event, whatever their dates
+    pub flag_recurring: bool,
+    /// Warn about event titles naming a month the event isn't in. This is only a heuristic, so it's off by default
+    pub cross_check_dates: bool,
+    /// If set, only report issues on these lines, e.g. the lines changed since the last commit. Lines are still all
+    /// read, so issues elsewhere don't stop us checking these
+    pub changed_lines: Option<HashSet<usize>>,
+}
+
+impl Default for LinterConfig {
+    fn default() -> Self {
+        Self {
+            should_edit: false,
+            error_limit: 20,
+            upgrade_http: false,
+            sort_organizers: false,
+            sort_events: false,
+            range_exclusive_end: false,
+            structure_only: false,
+            resync_on_region: false,
+            require_events: false,
+            max_range_days: 40,
+            max_days_after_range: 365,
+            allowed_hosts: None,
+            deny_warnings: false,
+            flag_recurring: false,
+            cross_check_dates: false,
+            changed_lines: None,
+        }
+    }
+}
+
+/// Lines we've already parsed by their content, so linting the same draft again (e.g. each time it's saved in watch
+/// mode) only parses the lines that changed. Only lines that parsed successfully are kept, and only from the last run,
+/// so lines that were edited away don't build up
+#[derive(Debug, Default)]
+pub struct LineCache {
+    /// Lines from the previous run we haven't seen in this one yet
+    previous: HashMap<String, EventLineType>,
+    /// Lines seen in this run, which become the next run's cache
+    current: HashMap<String, EventLineType>,
+}
+
+impl LineCache {
+    fn get(&mut self, line: &str) -> Option<EventLineType> {
+        if let Some(line_type) = self.current.get(line) {
+            return Some(line_type.clone());
+        }
+
+        let line_type = self.previous.remove(line)?;
+        self.current.insert(line.to_owned(), line_type.clone());
+        Some(line_type)
+    }
+
+    fn insert(&mut self, line: &str, line_type: EventLineType) {
+        self.current.insert(line.to_owned(), line_type);
+    }
+
+    /// The cache for the next run, dropping any lines we didn't see in this one
+    fn next_run(self) -> Self {
+        Self {
+            previous: self.current,
+            current: HashMap::new(),
+        }
+    }
+}
+
 // TODO: keep track of newlines here, like in a counter? So we can lint for unexpected newlines between sections
 #[derive(Debug)]
 pub struct EventSectionLinter {
@@ -207,148 +977,727 @@ pub struct EventSectionLinter {
     linter_state: LinterState,
     /// Date range - this is unknown until we reach the date range line. Used for validating dates fall within the given range
     event_date_range: Option<(NaiveDate, NaiveDate)>,
+    /// A date range we found before the events section started, used if the section doesn't have its own
+    preamble_date_range: Option<(NaiveDate, NaiveDate)>,
     /// Region we are in
     current_region: Option<String>,
     /// The last event in our current region. Used to make sure we have our events properly sorted by date and location name
     previous_event: Option<EventDateLocation>,
-    /// Whether we should make edits or not, if enabled we will print out each (potentially edited) line
-    should_edit: bool,
-    /// Maximum error count before bailing
-    error_limit: u32,
+    /// Options for this run of the linter
+    config: LinterConfig,
+    /// The draft with our edits applied, only populated if we are making edits
+    edited: String,
+    /// A replacement for the line we are reading, for edits that need the parsed line to make
+    rewritten_line: Option<String>,
+    /// Errors we have found so far, in the order we found them
+    errors: Vec<LintDiagnostic>,
+    /// Warnings we have found so far - these are reported but don't fail the lint
+    warnings: Vec<LintDiagnostic>,
+    /// Line number of the line we are currently reading, used when reporting warnings
+    line_num: usize,
+    /// Whether we've already tolerated a note line under the current region header
+    seen_region_note: bool,
+    /// Number of consecutive blank lines directly before the line we are reading
+    blank_lines: usize,
+    /// The overview line of the event we are reading, waiting on its event links
+    current_overview: Option<EventOverview>,
+    /// The overview of the listing we last added, further event lines under it belong to this listing. Unset when the
+    /// overview or first event line of the current event had an error, so its event lines aren't added to another listing
+    listing_overview: Option<EventOverview>,
+    /// Every event listing we've read so far
+    events: EventsByRegion,
+    /// Lints to skip on the line we are reading, from an ignore directive on the line before it
+    ignored_lints: Vec<String>,
+    /// Lints skipped on each line by an ignore directive, for checks that report issues on earlier lines
+    line_ignored_lints: HashMap<usize, Vec<String>>,
+    /// Name each group URL was first listed with, so we can make sure groups are named consistently
+    group_names: HashMap<Url, String>,
+    /// Line each event title was first seen on in the current region, to catch titles pasted onto the wrong event
+    region_titles: HashMap<String, usize>,
+    /// Region headers we've seen so far, in order
+    seen_regions: Vec<String>,
+    /// Number of event overview lines we've read, whether or not they had errors
+    event_count: usize,
+    /// Lines each event link is listed on, so we can report duplicated links once we've read the whole section
+    event_url_lines: HashMap<Url, Vec<usize>>,
+    /// The first line each group and (unbolded) event title is listed on, for flagging recurring events listed twice
+    group_title_lines: HashMap<(Url, String), usize>,
+    /// Number of errors we've found so far, including warnings we've treated as errors
+    error_count: u32,
+    /// Lines parsed on previous runs
+    line_cache: LineCache,
+    /// Number of lines we've parsed from scratch, rather than taking from the cache
+    lines_parsed: usize,
 }
 
 impl Default for EventSectionLinter {
     fn default() -> Self {
-        Self::new(false, 20)
+        Self::new(LinterConfig::default())
     }
 }
 
 impl EventSectionLinter {
-    pub fn new(should_edit: bool, error_limit: u32) -> Self {
+    pub fn new(config: LinterConfig) -> Self {
         Self {
             linter_state: LinterState::new(),
             event_date_range: None,
+            preamble_date_range: None,
             current_region: None,
             previous_event: None,
-            should_edit,
-            error_limit,
+            config,
+            edited: String::new(),
+            rewritten_line: None,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            line_num: 0,
+            seen_region_note: false,
+            blank_lines: 0,
+            current_overview: None,
+            listing_overview: None,
+            events: EventsByRegion::default(),
+            group_names: HashMap::new(),
+            region_titles: HashMap::new(),
+            seen_regions: Vec::new(),
+            event_count: 0,
+            ignored_lints: Vec::new(),
+            line_ignored_lints: HashMap::new(),
+            event_url_lines: HashMap::new(),
+            group_title_lines: HashMap::new(),
+            error_count: 0,
+            line_cache: LineCache::default(),
+            lines_parsed: 0,
         }
     }
 
-    pub fn lint(&mut self, md: &str) -> Result<(), LintError> {
-        let lines: Vec<&str> = md.lines().collect();
-        let mut error_count: u32 = 0;
+    /// A linter reusing the lines parsed by a previous linter, see `into_cache`
+    pub fn with_cache(config: LinterConfig, line_cache: LineCache) -> Self {
+        Self {
+            line_cache,
+            ..Self::new(config)
+        }
+    }
 
-        let mut skip_next = false;
+    /// Our parsed lines, to hand to the next linter when linting the same draft again
+    pub fn into_cache(self) -> LineCache {
+        self.line_cache.next_run()
+    }
 
-        for (i, line) in lines.iter().enumerate() {
-            if skip_next {
-                info!("Skipping line #{}:'{}'", i + 1, line);
-                skip_next = false;
-                continue;
-            }
+    /// Whether we stopped linting early because we found too many errors, so we may not have read every event
+    pub fn reached_error_limit(&self) -> bool {
+        self.config.error_limit > 0 && self.error_count >= self.config.error_limit
+    }
 
-            match self.read_line(i, line) {
-                Ok(_) => {
-                    // TODO: actually should probably just save our input as a String so we can re-run it through the linter
-                    if self.should_edit {
-                        println!("{}", line);
-                    }
-                }
-                Err(e) => {
-                    // we don't care about any errors before the event section, which we expect a lot of because it's
-                    // not modeled in our linter
-                    // TODO: clean this up, we are just assuming all headers ("###") are regions, which is the source of the errors
-                    if self.linter_state == LinterState::PreEvents {
-                        if self.should_edit {
-                            println!("{}", line);
-                        }
-                        continue;
-                    }
+    /// Whether we couldn't read the events section at all given the result of `lint`: it's missing, it has no date
+    /// range, or the file ends partway through it. Anything else still leaves us events we can count
+    pub fn section_unreadable(&self, result: &Result<(), LintError>) -> bool {
+        *result == Err(LintError::UnexpectedEnd) || self.event_date_range.is_none()
+    }
 
-                    // handle recoverable errors if we are editing the draft
-                    if self.should_edit {
-                        if let LintError::EventOutOfDateRange { .. } = e {
-                            info!("Removing stale event on line #{}: {}", i + 1, line);
-                            skip_next = true;
-                            continue;
-                        }
-                    }
+    /// Number of lines we had to parse, rather than finding them in the cache
+    pub fn lines_parsed(&self) -> usize {
+        self.lines_parsed
+    }
 
-                    error!(
-                        "Linter Error:\n{}\nCaused by line #{}: '{}'",
-                        e,
-                        i + 1,
-                        line
-                    );
+    /// The newsletter's date range, once we've read the date range line
+    pub fn date_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.event_date_range
+    }
 
-                    // attempt to continue to parse, this could print out a bunch of errors in some cases
-                    self.linter_state = self.linter_state.next()?;
+    /// Event listings read while linting, this only includes events that passed linting
+    pub fn events(&self) -> &EventsByRegion {
+        &self.events
+    }
 
-                    error_count += 1;
+    /// The draft with our edits applied, this is empty unless we are making edits
+    pub fn edited(&self) -> &str {
+        &self.edited
+    }
 
-                    // if we reach this many errors something has probably gone very wrong, so just exit early
-                    // rather than overwhelming the output with more error messages
-                    if error_count == self.error_limit {
-                        error!("Reached our maximum error limit, bailing");
-                        return Err(LintError::LintFailed);
-                    }
-                }
-            }
-        }
+    /// Errors found while linting, in the order we found them
+    pub fn errors(&self) -> &[LintDiagnostic] {
+        &self.errors
+    }
 
-        if self.linter_state != LinterState::Done {
-            return Err(LintError::UnexpectedEnd);
+    /// Warnings found while linting, in the order we found them
+    pub fn warnings(&self) -> &[LintDiagnostic] {
+        &self.warnings
+    }
+
+    /// Event links listed more than once with every line they're listed on, in the order they first appear
+    pub fn duplicate_event_urls(&self) -> Vec<(Url, Vec<usize>)> {
+        let mut duplicates: Vec<(Url, Vec<usize>)> = self
+            .event_url_lines
+            .iter()
+            .filter(|(_, lines)| lines.len() > 1)
+            .map(|(url, lines)| (url.clone(), lines.clone()))
+            .collect();
+        duplicates.sort_by_key(|(_, lines)| lines[0]);
+        duplicates
+    }
+
+    /// Every group we've seen, with the name it was first listed under, sorted by URL. Each group is listed once however
+    /// many events it has
+    pub fn organizers(&self) -> Vec<(&Url, &str)> {
+        let mut organizers: Vec<(&Url, &str)> = self
+            .group_names
+            .iter()
+            .map(|(url, name)| (url, name.as_str()))
+            .collect();
+        organizers.sort_by_key(|(url, _)| url.as_str());
+        organizers
+    }
+
+    /// Report a non-fatal issue with the line we are currently reading
+    fn warn(&mut self, warning: LintError) {
+        if self.is_ignored(&warning) {
+            return;
         }
 
-        if error_count > 0 {
-            Err(LintError::LintFailed)
-        } else {
-            Ok(())
+        self.warn_at(self.line_num, warning);
+    }
+
+    /// Report a non-fatal issue with an earlier line, for checks that need the whole section
+    fn warn_at(&mut self, line_num: usize, warning: LintError) {
+        let ignored = self
+            .line_ignored_lints
+            .get(&line_num)
+            .is_some_and(|lints| lints.iter().any(|lint| lint == warning.name()));
+        if ignored {
+            info!("Ignoring {} on line #{}", warning.name(), line_num);
+            return;
         }
+
+        self.report(line_num, warning);
     }
 
-    fn read_line(&mut self, line_num: usize, line: &str) -> Result<(), LintError> {
-        let line_type = line.parse::<EventLineType>()?;
-        debug!(
-            "In state {}, parsed line #{} '{}' as '{:?}'",
-            self.linter_state.to_string(),
-            line_num,
-            line,
-            line_type
-        );
+    /// Records an issue as an error or a warning by its severity, errors count towards the error limit and fail the lint
+    fn report(&mut self, line_num: usize, error: LintError) {
+        if !self.is_reported(line_num) {
+            debug!("Not reporting unchanged line #{}: {}", line_num, error);
+            return;
+        }
 
-        match &self.linter_state {
-            LinterState::PreEvents => self.handle_pre_events(line_type),
-            LinterState::ExpectingDateRange => self.handle_expected_date_range(line_type),
-            LinterState::ExpectingRegionalHeader => {
-                self.handle_expecting_regional_header(line_type)
+        let severity = self.reported_severity(&error);
+        match severity {
+            Severity::Error => {
+                error!("Linter Error:\n{}\nCaused by line #{}", error, line_num);
+                self.errors.push(LintDiagnostic {
+                    line_num,
+                    error,
+                    severity,
+                });
+                self.error_count += 1;
             }
-            LinterState::ExpectingEventDateLocationGroupLink => {
-                self.handle_expecting_event_date_location_group_link(line_type)
+            Severity::Warning => {
+                warn!("Linter Warning:\n{}\nCaused by line #{}", error, line_num);
+                self.warnings.push(LintDiagnostic {
+                    line_num,
+                    error,
+                    severity,
+                });
             }
-            LinterState::ExpectingEventNameLink => self.handle_expecting_event_name_link(line_type),
-            LinterState::Done => Ok(()),
         }
     }
 
-    /// Handler before we are in the events section. Accepts all lines and just continues until we hit the event section
-    fn handle_pre_events(&mut self, line_type: EventLineType) -> Result<(), LintError> {
-        match line_type {
-            EventLineType::StartEventSection => {
-                self.linter_state = self.linter_state.next()?;
-                Ok(())
-            }
-            _ => Ok(()),
+    /// The severity we report a lint with, its own severity unless an option makes it an error
+    fn reported_severity(&self, error: &LintError) -> Severity {
+        let made_error = self.config.deny_warnings
+            || (self.config.require_events && *error == LintError::NoEventsFound);
+        if made_error {
+            Severity::Error
+        } else {
+            error.severity()
         }
     }
 
-    /// Handler to run when we are expecting to receive a date range line
+    /// Whether we report issues on the given line, we only report issues on changed lines if we know which changed
+    fn is_reported(&self, line_num: usize) -> bool {
+        self.config
+            .changed_lines
+            .as_ref()
+            .is_none_or(|lines| lines.contains(&line_num))
+    }
+
+    pub fn lint(&mut self, md: &str) -> Result<(), LintError> {
+        self.lint_lines(md, None)
+    }
+
+    /// Like `lint`, but each warning and error is handed to `visitor` as soon as it's found rather than being kept, so
+    /// `errors()` and `warnings()` stay empty. For very large inputs, e.g. linting a whole archive of drafts
+    pub fn lint_with_visitor(
+        &mut self,
+        md: &str,
+        mut visitor: impl FnMut(LintDiagnostic),
+    ) -> Result<(), LintError> {
+        let result = self.lint_lines(md, Some(&mut visitor));
+        // pick up anything from the last line, or from bailing early
+        self.flush_diagnostics(&mut Some(&mut visitor));
+        result
+    }
+
+    /// Hands our diagnostics so far to the visitor in line order, if we have one
+    fn flush_diagnostics(&mut self, visitor: &mut Option<&mut dyn FnMut(LintDiagnostic)>) {
+        if let Some(visitor) = visitor {
+            let mut diagnostics: Vec<LintDiagnostic> = self
+                .warnings
+                .drain(..)
+                .chain(self.errors.drain(..))
+                .collect();
+            diagnostics.sort_by_key(|diagnostic| diagnostic.line_num);
+            for diagnostic in diagnostics {
+                visitor(diagnostic);
+            }
+        }
+    }
+
+    fn lint_lines(
+        &mut self,
+        md: &str,
+        mut visitor: Option<&mut dyn FnMut(LintDiagnostic)>,
+    ) -> Result<(), LintError> {
+        let mut skip_next = false;
+        // after an error with resync_on_region, we skip lines until the next region header or the footer
+        let mut resyncing = false;
+
+        // read every line in the file (rather than just the events section) so our line numbers always match the file's
+        // line numbers
+        for line_info in Reader::new(md) {
+            let (line_num, line) = (line_info.num(), line_info.text());
+            self.flush_diagnostics(&mut visitor);
+
+            if skip_next {
+                info!("Skipping line #{}:'{}'", line_num, line);
+                skip_next = false;
+                continue;
+            }
+
+            if resyncing {
+                match line.parse::<EventLineType>() {
+                    Ok(EventLineType::EventRegionHeader(_) | EventLineType::EndEventSection) => {
+                        info!("Resyncing on line #{}: '{}'", line_num, line);
+                        resyncing = false;
+                        self.linter_state = LinterState::ExpectingRegionalHeader;
+                        self.previous_event = None;
+                        self.current_region = None;
+                        self.current_overview = None;
+                        self.listing_overview = None;
+                    }
+                    _ => {
+                        info!("Skipping line #{} while resyncing: '{}'", line_num, line);
+                        if self.config.should_edit {
+                            self.edited.push_str(line);
+                            self.edited.push('\n');
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            if line_info.is_ambiguous_continuation() {
+                // we can't tell if this is the rest of the title above or a stray line, so leave our state alone
+                self.report(
+                    line_num,
+                    LintError::AmbiguousContinuation { line: line_num },
+                );
+                if self.config.should_edit {
+                    self.edited.push_str(line);
+                    self.edited.push('\n');
+                }
+                continue;
+            }
+
+            match self.read_line(line_num, line) {
+                Ok(_) => {
+                    if self.config.should_edit {
+                        let edited_line = self.edit_line(line);
+                        self.edited.push_str(&edited_line);
+                        self.edited.push('\n');
+                    }
+                }
+                Err(e) => {
+                    // handle recoverable errors if we are editing the draft
+                    if self.config.should_edit {
+                        if let LintError::EventOutOfDateRange { .. } = e {
+                            info!("Removing stale event on line #{}: {}", line_num, line);
+                            skip_next = true;
+                            continue;
+                        }
+                    }
+
+                    // we still need to recover from errors on lines we don't report
+                    debug!("Error on line #{}: '{}'", line_num, line);
+                    self.report(line_num, e);
+
+                    // attempt to continue to parse, this could print out a bunch of errors in some cases unless we
+                    // skip ahead to the next region
+                    if self.config.resync_on_region {
+                        resyncing = true;
+                    } else {
+                        self.linter_state = self.linter_state.next()?;
+                    }
+                }
+            }
+
+            // if we reach this many errors something has probably gone very wrong, so just exit early rather than
+            // overwhelming the output with more error messages. A limit of 0 means no limit
+            if self.config.error_limit > 0 && self.error_count >= self.config.error_limit {
+                error!("Reached our maximum error limit, bailing");
+                return Err(LintError::LintFailed);
+            }
+        }
+
+        if self.config.should_edit && self.config.sort_events {
+            self.edited = Self::sort_listings(&self.edited);
+        }
+
+        // the draft may be truncated partway through the events section, in which case we never see the footer
+        if self.linter_state != LinterState::Done {
+            error!(
+                "Reached the end of the file in linter state '{}'",
+                self.linter_state
+            );
+            return Err(LintError::UnexpectedEnd);
+        }
+
+        // a section with no regions at all is well formed, but probably not what anyone wanted to publish
+        if self.event_count == 0 {
+            self.warn(LintError::NoEventsFound);
+        }
+
+        // report each duplicated link once, on the first line it's repeated on
+        for (url, lines) in self.duplicate_event_urls() {
+            self.warn_at(
+                lines[1],
+                LintError::DuplicateEventUrl {
+                    url: url.to_string(),
+                    lines,
+                },
+            );
+        }
+
+        if self.error_count > 0 {
+            Err(LintError::LintFailed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks on the raw text of a line that don't depend on the linter state, these only produce warnings
+    fn check_line(&mut self, line: &str, line_type: &EventLineType) {
+        match line_type {
+            EventLineType::EventsDateRange(..) => match line.trim_end().split_once(CRAB_EMOJI) {
+                Some((_, "")) => (),
+                Some((_, trailing)) => self.warn(LintError::DateRangeTrailingText {
+                    line: self.line_num,
+                    trailing: trailing.trim_start().to_owned(),
+                }),
+                None => self.warn(LintError::MissingCrabEmoji),
+            },
+            EventLineType::EventDateLocationGroup(_)
+                if !EVENT_DATE_LOCATION_SPACING_RE.is_match(line) =>
+            {
+                self.warn(LintError::InconsistentSpacing);
+            }
+            EventLineType::EventRegionHeader(region) => {
+                match line.strip_prefix(EVENT_REGION_HEADER) {
+                    Some(found) if !found.starts_with([' ', '#']) => {
+                        let normalized = normalize_whitespace(found);
+                        if normalized != found {
+                            self.warn(LintError::RegionHeaderWhitespace {
+                                line: self.line_num,
+                            });
+                        }
+                        if normalized != *region {
+                            self.warn(LintError::RegionCasing {
+                                found: normalized,
+                                expected: region.to_owned(),
+                            });
+                        }
+                    }
+                    _ => self.warn(LintError::RegionHeaderFormatting(line.to_owned())),
+                }
+            }
+            EventLineType::EventName(_) if !line.starts_with(EVENT_NAME_HINT) => {
+                self.warn(LintError::DetailIndentation {
+                    line: self.line_num,
+                    spaces: line.len() - line.trim_start_matches(' ').len(),
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// Sorts each run of listings in an edited draft's events section by date and location, the same order we check
+    /// for. Ignore directives and event lines move along with their overview line, and nothing outside of the section
+    /// is moved
+    fn sort_listings(edited: &str) -> String {
+        // only the lines after the section's header and before its footer are sorted
+        let lines: Vec<&str> = edited.lines().collect();
+        let start = lines
+            .iter()
+            .position(|line| *line == START_EVENTS_SECTION)
+            .map_or(lines.len(), |header| header + 1);
+        let end = lines[start..]
+            .iter()
+            .position(|line| {
+                matches!(
+                    line.parse::<EventLineType>(),
+                    Ok(EventLineType::EndEventSection)
+                )
+            })
+            .map_or(lines.len(), |footer| start + footer);
+
+        let mut output = String::new();
+        for line in &lines[..start] {
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        let mut listings: Vec<(EventDateLocation, String)> = Vec::new();
+        // ignore directives we've read since the last listing, these belong to whatever comes next
+        let mut pending = String::new();
+
+        let flush = |output: &mut String, listings: &mut Vec<(EventDateLocation, String)>| {
+            // a stable sort, so events that are already in order stay as they are
+            listings.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (_, listing) in listings.drain(..) {
+                output.push_str(&listing);
+            }
+        };
+
+        for line in &lines[start..end] {
+            match line.parse::<EventLineType>() {
+                Ok(EventLineType::EventDateLocationGroup(overview)) => {
+                    let listing = format!("{}{}\n", pending, line);
+                    listings.push((overview.date_location().clone(), listing));
+                    pending.clear();
+                }
+                Ok(EventLineType::IgnoreDirective(_)) => {
+                    pending.push_str(line);
+                    pending.push('\n');
+                }
+                _ if line.starts_with(' ') && !listings.is_empty() => {
+                    if let Some((_, listing)) = listings.last_mut() {
+                        listing.push_str(&pending);
+                        listing.push_str(line);
+                        listing.push('\n');
+                    }
+                    pending.clear();
+                }
+                _ => {
+                    flush(&mut output, &mut listings);
+                    output.push_str(&pending);
+                    output.push_str(line);
+                    output.push('\n');
+                    pending.clear();
+                }
+            }
+        }
+        flush(&mut output, &mut listings);
+        output.push_str(&pending);
+        for line in &lines[end..] {
+            output.push_str(line);
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Applies any edits we make to a line that passed linting
+    fn edit_line<'a>(&mut self, line: &'a str) -> Cow<'a, str> {
+        match self.rewritten_line.take() {
+            Some(rewritten) => Cow::Owned(rewritten),
+            None => Cow::Borrowed(line),
+        }
+    }
+
+    /// Warns about any links that aren't https, unless we're going to upgrade them
+    fn check_url_schemes<'a>(&mut self, links: impl Iterator<Item = &'a MarkdownLink>) {
+        for link in links {
+            let url = link.url();
+            let will_upgrade =
+                self.config.should_edit && self.config.upgrade_http && url.scheme() == "http";
+
+            if url.scheme() != "https" && !will_upgrade {
+                self.warn(LintError::NonHttpsUrl(url.to_string()));
+            }
+        }
+    }
+
+    /// Whether the given lint is skipped for the line we are currently reading
+    fn is_ignored(&self, error: &LintError) -> bool {
+        self.ignored_lints.iter().any(|lint| lint == error.name())
+    }
+
+    /// Returns the error, unless it's been skipped for the line we are currently reading by an ignore directive
+    fn unless_ignored(&self, error: LintError) -> Result<(), LintError> {
+        if self.is_ignored(&error) {
+            info!("Ignoring {} on line #{}", error.name(), self.line_num);
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    fn read_line(&mut self, line_num: usize, line: &str) -> Result<(), LintError> {
+        self.line_num = line_num;
+        self.rewritten_line = None;
+
+        // nothing outside of the events section is modeled by the linter, so rather than parsing those lines (and
+        // getting spurious errors for things like non-region "###" headers) we just look for the start of the section
+        match self.linter_state {
+            LinterState::PreEvents => {
+                if line.eq_ignore_ascii_case(START_EVENTS_SECTION) {
+                    if line != START_EVENTS_SECTION {
+                        self.warn(LintError::HeaderCasing {
+                            line: self.line_num,
+                        });
+                        if self.config.should_edit {
+                            self.rewritten_line = Some(START_EVENTS_SECTION.to_owned());
+                        }
+                    }
+                    self.linter_state = self.linter_state.next()?;
+                } else if line.starts_with(EVENTS_DATE_RANGE_HINT) {
+                    self.check_preamble_date_range(line);
+                }
+                return Ok(());
+            }
+            LinterState::Done => return Ok(()),
+            _ => (),
+        }
+
+        // text copy-pasted from some sites has non-breaking spaces where we expect regular ones
+        let line = if line.contains(NON_BREAKING_SPACE) {
+            self.warn(LintError::NonBreakingSpace);
+            let normalized = line.replace(NON_BREAKING_SPACE, " ");
+            if self.config.should_edit {
+                self.rewritten_line = Some(normalized.clone());
+            }
+            Cow::Owned(normalized)
+        } else {
+            Cow::Borrowed(line)
+        };
+        // zero-width characters are invisible, but make titles and links differ from ones that look the same
+        let line = if line.contains(ZERO_WIDTH_CHARACTERS) {
+            self.warn(LintError::ZeroWidthCharacter {
+                line: self.line_num,
+            });
+            let stripped = line.replace(ZERO_WIDTH_CHARACTERS, "");
+            if self.config.should_edit {
+                self.rewritten_line = Some(stripped.clone());
+            }
+            Cow::Owned(stripped)
+        } else {
+            line
+        };
+        let line = line.as_ref();
+
+        let line_type = match self.line_cache.get(line) {
+            Some(line_type) => line_type,
+            None => {
+                let line_type = line.parse::<EventLineType>()?;
+                self.lines_parsed += 1;
+                self.line_cache.insert(line, line_type.clone());
+                line_type
+            }
+        };
+        debug!(
+            "In state {}, parsed line #{} '{}' as '{:?}'",
+            self.linter_state.to_string(),
+            line_num,
+            line,
+            line_type
+        );
+
+        // ignore directives only apply to the line after them, so save them off and move on to the next line
+        if let EventLineType::IgnoreDirective(lints) = line_type {
+            self.ignored_lints = lints;
+            self.blank_lines = 0;
+            return Ok(());
+        }
+        if !self.ignored_lints.is_empty() {
+            self.line_ignored_lints
+                .insert(line_num, self.ignored_lints.clone());
+        }
+
+        self.check_line(line, &line_type);
+
+        let is_newline = line_type == EventLineType::Newline;
+
+        let result = match &self.linter_state {
+            LinterState::PreEvents | LinterState::Done => Ok(()),
+            // there should only be one date range, wherever another one turns up
+            _ if matches!(line_type, EventLineType::EventsDateRange(..))
+                && self.event_date_range.is_some() =>
+            {
+                Err(LintError::DuplicateDateRange {
+                    line: self.line_num,
+                })
+            }
+            LinterState::ExpectingDateRange => self.handle_expected_date_range(line_type),
+            LinterState::ExpectingRegionalHeader => {
+                self.handle_expecting_regional_header(line_type)
+            }
+            LinterState::ExpectingEventDateLocationGroupLink => {
+                self.handle_expecting_event_date_location_group_link(line_type)
+            }
+            LinterState::ExpectingEventNameLink => self.handle_expecting_event_name_link(line_type),
+        };
+
+        // only upgrade link targets in the events section, so we don't touch any other text, non-web schemes, or
+        // lines we haven't linted
+        if result.is_ok()
+            && self.config.should_edit
+            && self.config.upgrade_http
+            && line.contains(HTTP_LINK_START)
+        {
+            self.warn(LintError::UpgradedHttpLinks {
+                line: self.line_num,
+            });
+            let rewritten = self
+                .rewritten_line
+                .take()
+                .unwrap_or_else(|| line.to_owned());
+            self.rewritten_line = Some(rewritten.replace(HTTP_LINK_START, HTTPS_LINK_START));
+        }
+
+        self.blank_lines = if is_newline { self.blank_lines + 1 } else { 0 };
+        self.ignored_lints.clear();
+
+        result
+    }
+
+    /// Reports a date range line before the events section, keeping its range in case the section doesn't have one
+    fn check_preamble_date_range(&mut self, line: &str) {
+        let Ok(EventLineType::EventsDateRange(start, end)) = line.parse::<EventLineType>() else {
+            return;
+        };
+        self.preamble_date_range = Some((start, end));
+
+        let e = LintError::DateRangeBeforeSection {
+            line: self.line_num,
+        };
+        if !self.is_ignored(&e) {
+            self.report(self.line_num, e);
+        }
+    }
+
+    /// Handler to run when we are expecting to receive a date range line
     fn handle_expected_date_range(&mut self, line_type: EventLineType) -> Result<(), LintError> {
         match line_type {
             EventLineType::Newline => Ok(()),
             EventLineType::EventsDateRange(start_date, end_date) => {
                 if self.event_date_range.is_none() {
+                    let days = (end_date - start_date).num_days();
+                    if end_date.year() - start_date.year() > 1
+                        || days < 0
+                        || days > i64::from(self.config.max_range_days)
+                    {
+                        self.warn(LintError::SuspiciousDateRange {
+                            start: start_date,
+                            end: end_date,
+                        });
+                    }
+
                     self.event_date_range = Some((start_date, end_date));
                     self.linter_state = self.linter_state.next()?;
                     Ok(())
@@ -356,6 +1705,17 @@ impl EventSectionLinter {
                     Err(LintError::UnexpectedDateRange)
                 }
             }
+            // we already reported a date range before the section, so use it rather than failing every event
+            EventLineType::EventRegionHeader(_) if self.preamble_date_range.is_some() => {
+                self.event_date_range = self.preamble_date_range;
+                self.linter_state = self.linter_state.next()?;
+                self.handle_expecting_regional_header(line_type)
+            }
+            // the date range line is optional when we only check structure, so go straight to the first region
+            EventLineType::EventRegionHeader(_) if self.config.structure_only => {
+                self.linter_state = self.linter_state.next()?;
+                self.handle_expecting_regional_header(line_type)
+            }
             _ => Err(LintError::UnexpectedLineType {
                 linter_state: self.linter_state.to_string(),
                 line_type: line_type.to_string(),
@@ -374,15 +1734,33 @@ impl EventSectionLinter {
         match line_type {
             EventLineType::Newline => Ok(()),
             EventLineType::EventRegionHeader(region) => {
+                // Virtual events always go first
+                if region == VIRTUAL_LOCATION && !self.seen_regions.is_empty() {
+                    self.warn(LintError::VirtualNotFirst);
+                }
+                self.seen_regions.push(region.clone());
+
                 // TODO: check if region is already set?
                 self.current_region = Some(region);
+                self.seen_region_note = false;
+                self.region_titles.clear();
                 self.linter_state = self.linter_state.next()?;
                 Ok(())
             }
             EventLineType::EndEventSection => {
+                if self.blank_lines != 1 {
+                    self.warn(LintError::FooterSpacing {
+                        blank_lines: self.blank_lines,
+                    });
+                }
                 self.linter_state = self.linter_state.finish()?;
                 Ok(())
             }
+            EventLineType::EventDateLocationGroup(_) if self.current_region.is_none() => {
+                Err(LintError::EventBeforeRegion {
+                    line: self.line_num,
+                })
+            }
             _ => Err(LintError::UnexpectedLineType {
                 linter_state: self.linter_state.to_string(),
                 line_type: line_type.to_string(),
@@ -400,38 +1778,106 @@ impl EventSectionLinter {
         line_type: EventLineType,
     ) -> Result<(), LintError> {
         match line_type {
-            EventLineType::EventDateLocationGroup(event_date_location) => {
-                // validate event is within date range
-                if let Some(date_range) = &self.event_date_range {
-                    if (*event_date_location.date() < date_range.0)
-                        || (*event_date_location.date() > date_range.1)
-                    {
-                        return Err(LintError::EventOutOfDateRange {
-                            event_date: *event_date_location.date(),
-                            date_range: *date_range,
-                        });
+            EventLineType::EventDateLocationGroup(mut overview) => {
+                self.event_count += 1;
+                // until this event's first event line is accepted, there's no listing for more event lines to join
+                self.listing_overview = None;
+
+                // parsing requires a group link, but the rest of our checks assume there's at least one so make sure
+                if overview.groups().is_empty() {
+                    return Err(LintError::NoOrganizers {
+                        line: self.line_num,
+                    });
+                }
+
+                if let Some(canonical) = overview.date_location().canonical() {
+                    self.warn(LintError::NonCanonicalLocation {
+                        found: overview.date_location().location().to_owned(),
+                        expected: canonical.location().to_owned(),
+                    });
+                    overview = EventOverview::new(canonical, overview.groups().clone());
+                    if self.config.should_edit {
+                        self.rewritten_line = Some(overview.to_markdown());
+                    }
+                }
+
+                let mut groups = overview.groups().clone();
+                let duplicates = groups.dedup();
+                if !duplicates.is_empty() {
+                    for duplicate in duplicates {
+                        self.warn(LintError::DuplicateOrganizer(duplicate.label().to_owned()));
+                    }
+                    overview = EventOverview::new(overview.date_location().clone(), groups);
+                    if self.config.should_edit {
+                        self.rewritten_line = Some(overview.to_markdown());
+                    }
+                }
+
+                let event_date_location = overview.date_location();
+
+                // validate event is within date range, unless we're only checking structure
+                if !self.config.structure_only {
+                    if let Some(date_range) = self.event_date_range {
+                        let days_after_end =
+                            (event_date_location.date().sort_date() - date_range.1).num_days();
+                        if days_after_end > i64::from(self.config.max_days_after_range) {
+                            self.warn(LintError::ImplausibleFutureDate {
+                                event_date: event_date_location.date().sort_date(),
+                            });
+                        }
+
+                        if let Some(event_date) =
+                            self.date_out_of_scope(event_date_location.date(), &date_range)
+                        {
+                            self.unless_ignored(LintError::EventOutOfDateRange {
+                                event_date,
+                                date_range,
+                            })?;
+                        }
+                    // if we don't have the date range set, we are in an unexpected state
+                    } else {
+                        return Err(LintError::DateRangeNotSet);
                     }
-                // if we don't have the date range set, we are in an unexpected state
-                } else {
-                    return Err(LintError::DateRangeNotSet);
                 }
 
                 // if there is a previous event, compare to make sure our current one is later than the previous one
                 if let Some(previous_event) = &self.previous_event {
                     // TODO: make sure this comparison is correct
                     // if event_date_location > *previous_event {
-                    if event_date_location < *previous_event {
-                        return Err(LintError::EventOutOfOrder {
-                            event_date: *event_date_location.date(),
+                    if event_date_location < previous_event
+                        && self.config.should_edit
+                        && self.config.sort_events
+                    {
+                        info!("Moving event on line #{} into date order", self.line_num);
+                    } else if event_date_location < previous_event {
+                        self.unless_ignored(LintError::EventOutOfOrder {
+                            event_date: event_date_location.date().sort_date(),
                             event_location: event_date_location.location().to_owned(),
-                            previous_event_date: *previous_event.date(),
+                            previous_event_date: previous_event.date().sort_date(),
                             previous_event_location: previous_event.location().to_owned(),
-                        });
+                        })?;
                     }
                 }
 
+                self.check_hosts(overview.groups().iter())?;
+                self.check_group_names(&overview)?;
+                self.check_organizer_urls(&overview)?;
+                self.check_url_schemes(overview.groups().iter());
+
                 // and save our previous event so we can compare it when looking at the next event
-                self.previous_event = Some(event_date_location);
+                self.previous_event = Some(event_date_location.clone());
+
+                if self.config.sort_organizers {
+                    let mut groups = overview.groups().clone();
+                    groups.sort();
+
+                    if groups != *overview.groups() {
+                        overview = EventOverview::new(overview.date_location().clone(), groups);
+                        self.rewritten_line = Some(overview.to_markdown());
+                    }
+                }
+
+                self.current_overview = Some(overview);
                 self.linter_state = self.linter_state.next()?;
 
                 Ok(())
@@ -443,6 +1889,45 @@ impl EventSectionLinter {
                 self.previous_event = None;
                 // and reset our region to None as well
                 self.current_region = None;
+                self.listing_overview = None;
+                Ok(())
+            }
+            // another event line for the previous overview, e.g. two separate events at the same place and date
+            EventLineType::EventName(events) if self.previous_event.is_some() => {
+                let Some(overview) = self.listing_overview.clone() else {
+                    info!(
+                        "Dropping event line #{}, its event wasn't accepted",
+                        self.line_num
+                    );
+                    return Ok(());
+                };
+                self.check_events(Some(&overview), &events)?;
+
+                if let Some(listing) = self
+                    .current_region
+                    .as_deref()
+                    .and_then(|region| self.events.last_mut(region))
+                {
+                    listing.add_events(events);
+                }
+                Ok(())
+            }
+            // the footer directly after the last region's events, missing the blank line between them
+            EventLineType::EndEventSection => {
+                self.warn(LintError::FooterSpacing { blank_lines: 0 });
+                self.linter_state = self.linter_state.finish_regional_section()?.finish()?;
+                self.previous_event = None;
+                self.current_region = None;
+                self.listing_overview = None;
+                Ok(())
+            }
+            // some newsletters have a short note under the region header, tolerate a single one of these before the
+            // region's first event rather than cascading errors through the rest of the region
+            EventLineType::Unrecognized
+                if self.previous_event.is_none() && !self.seen_region_note =>
+            {
+                self.seen_region_note = true;
+                self.warn(LintError::UnexpectedRegionNote);
                 Ok(())
             }
             _ => Err(LintError::UnexpectedLineType {
@@ -456,56 +1941,2140 @@ impl EventSectionLinter {
         }
     }
 
-    fn handle_expecting_event_name_link(
-        &mut self,
-        line_type: EventLineType,
-    ) -> Result<(), LintError> {
-        match line_type {
-            EventLineType::EventName => {
-                self.linter_state = self.linter_state.next()?;
-                Ok(())
-            }
-            _ => Err(LintError::UnexpectedLineType {
-                linter_state: self.linter_state.to_string(),
-                line_type: line_type.to_string(),
-                expected_line_types: vec![EVENT_NAME_TYPE.to_string()],
-            }),
-        }
+    /// Whether an event date falls within the newsletter's date range. The start of the range is always inclusive, the
+    /// end is inclusive unless configured otherwise
+    fn date_in_scope(&self, date: &NaiveDate, date_range: &(NaiveDate, NaiveDate)) -> bool {
+        let before_end = if self.config.range_exclusive_end {
+            *date < date_range.1
+        } else {
+            *date <= date_range.1
+        };
+
+        *date >= date_range.0 && before_end
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// The first day of an event outside of the newsletter's date range, if any. Multi-day events only need to start
+    /// within the range, but each day of a recurring event needs to be within it
+    fn date_out_of_scope(
+        &self,
+        date: &EventDate,
+        date_range: &(NaiveDate, NaiveDate),
+    ) -> Option<NaiveDate> {
+        date.scheduled_days()
+            .into_iter()
+            .find(|day| !self.date_in_scope(day, date_range))
+    }
 
-    type TestResult = Result<(), Box<dyn std::error::Error>>;
+    /// Makes sure each link points at an allowed host, if we have an allowlist
+    fn check_hosts<'a>(
+        &self,
+        mut links: impl Iterator<Item = &'a MarkdownLink>,
+    ) -> Result<(), LintError> {
+        let Some(allowed_hosts) = &self.config.allowed_hosts else {
+            return Ok(());
+        };
 
-    fn build_event_section(body_to_add: Option<&str>) -> String {
-        let mut text = "some pre events section text\n".to_owned();
-        text.push_str("## Upcoming Events\n\n");
-        // just pushing each line separately to make it a little neater looking here, rather than one huge string literal
-        text.push_str("Rusty Events between 2024-10-23 - 2024-11-20 🦀\n\n");
-        text.push_str("### Virtual\n");
-        text.push_str(
-            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
-        );
-        text.push_str("    * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)\n");
-        text.push('\n');
+        let disallowed = links.find_map(|link| {
+            let host = link.url().host_str().unwrap_or_default();
+            let allowed = allowed_hosts
+                .iter()
+                .any(|allowed| host == allowed || host.ends_with(&format!(".{}", allowed)));
+            (!allowed).then(|| host.to_owned())
+        });
 
-        if let Some(lines) = body_to_add {
-            text.push_str(lines);
+        match disallowed {
+            Some(host) => self.unless_ignored(LintError::DisallowedHost(host)),
+            None => Ok(()),
         }
+    }
 
-        text.push_str("If you are running a Rust event please add it to the [calendar] to get\n");
-        text.push_str("it mentioned here. Please remember to add a link to the event too.\n");
-
-        text
+    /// Makes sure each event link has a path, a link to just a domain can't be a specific event
+    fn check_bare_domains<'a>(
+        &self,
+        mut links: impl Iterator<Item = &'a MarkdownLink>,
+    ) -> Result<(), LintError> {
+        match links.find(|link| matches!(link.url().path(), "" | "/")) {
+            Some(link) => self.unless_ignored(LintError::BareDomainUrl {
+                line: self.line_num,
+                url: link.url().to_string(),
+            }),
+            None => Ok(()),
+        }
     }
 
-    #[test]
-    fn test_valid_event_section() -> TestResult {
-        let mut linter = EventSectionLinter::default();
+    /// Makes sure each group in the overview has the same name as the first time we saw its URL, apostrophe lookalikes
+    /// aside
+    fn check_group_names(&mut self, overview: &EventOverview) -> Result<(), LintError> {
+        for group in overview.groups().iter() {
+            if group.label().contains(APOSTROPHE_LOOKALIKES) {
+                self.warn(LintError::NonAsciiApostrophe {
+                    line: self.line_num,
+                });
+            }
+
+            match self.group_names.get(&group.normalized_url()) {
+                Some(previous_name)
+                    if normalize_apostrophes(previous_name)
+                        != normalize_apostrophes(group.label()) =>
+                {
+                    self.unless_ignored(LintError::InconsistentGroupName {
+                        url: group.url().to_string(),
+                        name: group.label().to_owned(),
+                        previous_name: previous_name.to_owned(),
+                    })?;
+                }
+                Some(_) => (),
+                None => {
+                    self.group_names
+                        .insert(group.normalized_url(), group.label().to_owned());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Organizer links should go to the group's page rather than one of its events
+    fn check_organizer_urls(&mut self, overview: &EventOverview) -> Result<(), LintError> {
+        for group in overview.groups().iter() {
+            let url = group.normalized_url();
+            if url
+                .host()
+                .is_some_and(|host| host.to_owned() == *MEETUP_DOMAIN)
+                && MEETUP_EVENT_PATH_RE.is_match(url.path())
+            {
+                self.unless_ignored(LintError::OrganizerUrlTooSpecific(group.url().to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Warns if an event's title is just its group's name, usually a copy-paste of the wrong field
+    fn check_titles(&mut self, overview: &EventOverview, events: &Events) {
+        let duplicates: Vec<String> = events
+            .iter()
+            .map(|event| event.label().trim_matches('*'))
+            .filter(|title| {
+                overview
+                    .groups()
+                    .iter()
+                    .any(|group| group.label() == *title)
+            })
+            .map(|title| title.to_owned())
+            .collect();
+
+        for title in duplicates {
+            self.warn(LintError::TitleEqualsGroupName(title));
+        }
+    }
+
+    /// Warns about event titles we've already seen in this region
+    fn check_duplicate_titles(&mut self, events: &Events) {
+        for event in events.iter() {
+            let title = event.label().trim_matches('*').to_owned();
+            match self.region_titles.get(&title) {
+                Some(previous_line) => {
+                    let previous_line = *previous_line;
+                    self.warn(LintError::DuplicateTitle {
+                        title,
+                        previous_line,
+                    });
+                }
+                None => {
+                    self.region_titles.insert(title, self.line_num);
+                }
+            }
+        }
+    }
+
+    /// Notes when an event has a different number of organizers and event links, e.g. two groups running one event.
+    /// Both shapes are sometimes valid, so this is only a warning for a human to double check
+    fn check_link_counts(&mut self, overview: &EventOverview, events: &Events) {
+        let organizers = overview.groups().len();
+        let links = events.len();
+        if organizers != links {
+            self.warn(LintError::OrganizerLinkCountMismatch { organizers, links });
+        }
+    }
+
+    /// Warns if an event links to one of its organizers rather than to the event
+    fn check_event_urls(&mut self, overview: &EventOverview, events: &Events) {
+        let links_organizer = events.iter().any(|event| {
+            overview
+                .groups()
+                .iter()
+                .any(|group| group.normalized_url() == event.normalized_url())
+        });
+        if links_organizer {
+            self.warn(LintError::OrganizerEqualsEventUrl {
+                line: self.line_num,
+            });
+        }
+    }
+
+    /// Warns about an event title naming a month the event isn't in, e.g. a "November Meetup" listed in October. Only
+    /// whole, capitalized month names count, see `title_months`
+    fn check_title_months(&mut self, overview: &EventOverview, events: &Events) {
+        if !self.config.cross_check_dates {
+            return;
+        }
+
+        let event_months = overview.date_location().date().months();
+        let mismatch = events.iter().find_map(|event| {
+            Self::title_months(event.label())
+                .into_iter()
+                .find(|month| !event_months.contains(&month.number_from_month()))
+        });
+
+        if let Some(month) = mismatch {
+            self.warn(LintError::TitleMonthMismatch {
+                line: self.line_num,
+                month: month.name().to_owned(),
+            });
+        }
+    }
+
+    /// Months named in a title. Titles are usually title case, so capitals don't tell us if "May" is the month, and
+    /// month names that are also ordinary words only count next to a day, e.g. "May 5th" or "5 May"
+    fn title_months(title: &str) -> Vec<Month> {
+        let words: Vec<&str> = title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        // a day number, maybe with an ordinal suffix like "5th"
+        let is_day = |word: Option<&&str>| {
+            word.and_then(|word| {
+                let day = ["st", "nd", "rd", "th"]
+                    .iter()
+                    .find_map(|suffix| word.strip_suffix(suffix))
+                    .unwrap_or(word);
+                day.parse::<u32>().ok()
+            })
+            .is_some_and(|day| (1..=31).contains(&day))
+        };
+
+        words
+            .iter()
+            .enumerate()
+            .filter_map(|(i, word)| {
+                let month = (1..=12u8)
+                    .filter_map(|month| Month::try_from(month).ok())
+                    .find(|month| month.name() == *word)?;
+                let next_to_day = is_day(words.get(i + 1)) || i > 0 && is_day(words.get(i - 1));
+                (!AMBIGUOUS_MONTHS.contains(word) || next_to_day).then_some(month)
+            })
+            .collect()
+    }
+
+    /// Warns about an event with the same group and title as an earlier one, if we're looking for those. This happens
+    /// when a weekly event is listed once for each week by mistake. Returns whether we warned about the event
+    fn check_recurring(&mut self, overview: &EventOverview, events: &Events) -> bool {
+        if !self.config.flag_recurring {
+            return false;
+        }
+
+        let keys: Vec<(Url, String)> = overview
+            .groups()
+            .iter()
+            .flat_map(|group| {
+                events.iter().map(|event| {
+                    let title = event.label().trim_matches('*').trim().to_owned();
+                    (group.normalized_url(), title)
+                })
+            })
+            .collect();
+
+        let mut previous_line = None;
+        for key in keys {
+            match self.group_title_lines.get(&key) {
+                Some(line) => previous_line = previous_line.or(Some(*line)),
+                None => {
+                    self.group_title_lines.insert(key, self.line_num);
+                }
+            }
+        }
+
+        match previous_line {
+            Some(previous_line) => {
+                self.warn(LintError::PossibleRecurringDuplicate {
+                    line: self.line_num,
+                    previous_line,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Notes the line each event link is on, for finding duplicated links after reading the whole section
+    fn record_event_urls(&mut self, events: &Events) {
+        for event in events.iter() {
+            self.event_url_lines
+                .entry(event.normalized_url())
+                .or_default()
+                .push(self.line_num);
+        }
+    }
+
+    /// Checks on an event line, whether it's the first under its overview or another one after it. Checks comparing
+    /// the events with their overview are skipped if the overview had an error
+    fn check_events(
+        &mut self,
+        overview: Option<&EventOverview>,
+        events: &Events,
+    ) -> Result<(), LintError> {
+        self.check_hosts(events.iter())?;
+        self.check_bare_domains(events.iter())?;
+        self.check_url_schemes(events.iter());
+        if let Some(overview) = overview {
+            self.check_titles(overview, events);
+        }
+        // a recurring event listed twice would also have a duplicate title, but we say which it probably is
+        if !overview.is_some_and(|overview| self.check_recurring(overview, events)) {
+            self.check_duplicate_titles(events);
+        }
+        if let Some(overview) = overview {
+            self.check_link_counts(overview, events);
+            self.check_event_urls(overview, events);
+            self.check_title_months(overview, events);
+        }
+        self.record_event_urls(events);
+
+        Ok(())
+    }
+
+    fn handle_expecting_event_name_link(
+        &mut self,
+        line_type: EventLineType,
+    ) -> Result<(), LintError> {
+        match line_type {
+            EventLineType::EventName(events) => {
+                let overview = self.current_overview.take();
+                self.check_events(overview.as_ref(), &events)?;
+
+                if let (Some(region), Some(overview)) = (&self.current_region, overview) {
+                    self.listing_overview = Some(overview.clone());
+                    self.events.add(region, (overview, events).into());
+                }
+
+                self.linter_state = self.linter_state.next()?;
+                Ok(())
+            }
+            _ => Err(LintError::UnexpectedLineType {
+                linter_state: self.linter_state.to_string(),
+                line_type: line_type.to_string(),
+                expected_line_types: vec![EVENT_NAME_TYPE.to_string()],
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_support::SectionBuilder;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    fn build_event_section(body_to_add: Option<&str>) -> String {
+        build_event_section_with_preamble("some pre events section text\n", body_to_add)
+    }
+
+    fn build_event_section_with_preamble(preamble: &str, body_to_add: Option<&str>) -> String {
+        SectionBuilder::new()
+            .preamble(preamble)
+            .region("Virtual")
+            .event(
+                "2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "[**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .raw(body_to_add.unwrap_or_default())
+            .build()
+    }
+
+    #[test]
+    fn test_valid_event_section() -> TestResult {
+        let mut linter = EventSectionLinter::default();
         let text = build_event_section(None);
         Ok(linter.lint(&text)?)
     }
+
+    #[test]
+    fn test_preamble_is_not_parsed() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        // would fail to parse as a region header if we tried to parse the preamble
+        let text = build_event_section_with_preamble("### Not a region\n* not an event\n", None);
+        Ok(linter.lint(&text)?)
+    }
+
+    #[test]
+    fn test_line_numbers_match_file() -> TestResult {
+        // an event outside of our date range, so we get an error on the first event line
+        let body = "### Europe\n* 2024-12-24 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n    * [**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)\n\n";
+
+        // the preamble ending in a blank line (i.e. an extra trailing newline) should push everything down by one line
+        for (preamble, expected_line_num) in
+            [("line one\nline two\n", 12), ("line one\nline two\n\n", 13)]
+        {
+            let text = build_event_section_with_preamble(preamble, Some(body));
+
+            let mut linter = EventSectionLinter::default();
+            assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+
+            let errors = linter.errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].line_num(), expected_line_num);
+            assert!(matches!(
+                errors[0].error(),
+                LintError::EventOutOfDateRange { .. }
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_note() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        let text = build_event_section(None).replace(
+            "### Virtual\n",
+            "### Virtual\nAll times are in the local time zone of the event.\n",
+        );
+        linter.lint(&text)?;
+
+        let warnings = linter.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line_num(), 7);
+        assert_eq!(warnings[0].error(), &LintError::UnexpectedRegionNote);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_region_notes() {
+        let mut linter = EventSectionLinter::default();
+        let text = build_event_section(None).replace(
+            "### Virtual\n",
+            "### Virtual\nA note about this region.\nAnd another one.\n",
+        );
+        // only the first note is tolerated
+        assert!(linter.lint(&text).is_err());
+        let notes = linter
+            .warnings()
+            .iter()
+            .filter(|warning| warning.error() == &LintError::UnexpectedRegionNote);
+        assert_eq!(notes.count(), 1);
+        assert_eq!(linter.errors()[0].line_num(), 8);
+    }
+
+    #[test]
+    fn test_kind() -> TestResult {
+        let date = NaiveDate::default();
+        let line = 1;
+        let text = || "Rust Hack and Learn".to_owned();
+        let url = || "https://example.com/".to_owned();
+        let errors = [
+            LintError::InvalidStateChange { from: text() },
+            LintError::UnexpectedDateRange,
+            LintError::UnexpectedLineType {
+                linter_state: text(),
+                line_type: text(),
+                expected_line_types: vec![text()],
+            },
+            LintError::EventOutOfDateRange {
+                event_date: date,
+                date_range: (date, date),
+            },
+            LintError::EventOutOfOrder {
+                event_date: date,
+                event_location: text(),
+                previous_event_date: date,
+                previous_event_location: text(),
+            },
+            LintError::DateRangeNotSet,
+            LintError::RegexError {
+                regex_string: text(),
+            },
+            LintError::DateParseError {
+                chrono_error: "not a date".parse::<NaiveDate>().unwrap_err(),
+            },
+            LintError::ParseError,
+            LintError::UnexpectedEnd,
+            LintError::LintFailed,
+            LintError::InvalidUrl(url::ParseError::EmptyHost),
+            LintError::UnknownRegion(RegionParseError::new("Pangea")),
+            LintError::UrlContainsTracker(Url::parse(&url())?),
+            LintError::InvalidLinkLabel(text()),
+            LintError::UnexpectedRegionNote,
+            LintError::FooterSpacing { blank_lines: 2 },
+            LintError::InconsistentGroupName {
+                url: url(),
+                name: text(),
+                previous_name: text(),
+            },
+            LintError::NonHttpsUrl(url()),
+            LintError::MissingCrabEmoji,
+            LintError::DisallowedHost(url()),
+            LintError::InconsistentSpacing,
+            LintError::TitleEqualsGroupName(text()),
+            LintError::ParseFailed(ParseStage::Date),
+            LintError::NonCanonicalLocation {
+                found: text(),
+                expected: text(),
+            },
+            LintError::SuspiciousDateRange {
+                start: date,
+                end: date,
+            },
+            LintError::RegionCasing {
+                found: text(),
+                expected: text(),
+            },
+            LintError::OrganizerLinkCountMismatch {
+                organizers: 1,
+                links: 2,
+            },
+            LintError::OrganizerUrlTooSpecific(url()),
+            LintError::DuplicateTitle {
+                title: text(),
+                previous_line: line,
+            },
+            LintError::ImplausibleFutureDate { event_date: date },
+            LintError::DuplicateOrganizer(text()),
+            LintError::VirtualNotFirst,
+            LintError::NoEventsFound,
+            LintError::RegionHeaderFormatting(text()),
+            LintError::NonBreakingSpace,
+            LintError::DetailIndentation { line, spaces: 2 },
+            LintError::EventBeforeRegion { line },
+            LintError::MalformedPlatformUrl {
+                url: url(),
+                expected: "meetup.com/<group>/events/<id>",
+            },
+            LintError::DateRangeTrailingText {
+                line,
+                trailing: text(),
+            },
+            LintError::RegionHeaderWhitespace { line },
+            LintError::DuplicateEventUrl {
+                url: url(),
+                lines: vec![1, 2],
+            },
+            LintError::WrongDelimiter {
+                line: text(),
+                expected: text(),
+                found: text(),
+            },
+            LintError::DuplicateDateRange { line },
+            LintError::UnterminatedLink {
+                link: text(),
+                missing: ']',
+            },
+            LintError::OrganizerEqualsEventUrl { line },
+            LintError::HeaderCasing { line },
+            LintError::BareDomainUrl { line, url: url() },
+            LintError::NonAsciiApostrophe { line },
+            LintError::AmbiguousContinuation { line },
+            LintError::RelativeUrl { raw: text() },
+            LintError::PossibleRecurringDuplicate {
+                line,
+                previous_line: line,
+            },
+            LintError::TitleMonthMismatch {
+                line,
+                month: text(),
+            },
+            LintError::NoOrganizers { line },
+            LintError::ZeroWidthCharacter { line },
+            LintError::DateRangeBeforeSection { line },
+            LintError::UpgradedHttpLinks { line },
+            LintError::BackwardsEventDateRange {
+                start: date,
+                end: date,
+            },
+        ];
+
+        // kinds are meant to stay stable, so every lint is pinned here by name
+        let kinds: HashMap<&str, LintErrorKind> = HashMap::from([
+            ("InvalidStateChange", LintErrorKind::Structure),
+            ("UnexpectedDateRange", LintErrorKind::Structure),
+            ("UnexpectedLineType", LintErrorKind::Structure),
+            ("DateRangeNotSet", LintErrorKind::Structure),
+            ("UnexpectedEnd", LintErrorKind::Structure),
+            ("UnexpectedRegionNote", LintErrorKind::Structure),
+            ("EventBeforeRegion", LintErrorKind::Structure),
+            ("DuplicateDateRange", LintErrorKind::Structure),
+            ("AmbiguousContinuation", LintErrorKind::Structure),
+            ("NoEventsFound", LintErrorKind::Structure),
+            ("VirtualNotFirst", LintErrorKind::Structure),
+            ("NoOrganizers", LintErrorKind::Structure),
+            ("DateRangeBeforeSection", LintErrorKind::Structure),
+            ("RegexError", LintErrorKind::Parse),
+            ("DateParseError", LintErrorKind::Parse),
+            ("ParseError", LintErrorKind::Parse),
+            ("ParseFailed", LintErrorKind::Parse),
+            ("InvalidLinkLabel", LintErrorKind::Parse),
+            ("WrongDelimiter", LintErrorKind::Parse),
+            ("UnterminatedLink", LintErrorKind::Parse),
+            ("BackwardsEventDateRange", LintErrorKind::Parse),
+            ("EventOutOfOrder", LintErrorKind::OutOfOrder),
+            ("EventOutOfDateRange", LintErrorKind::OutOfRange),
+            ("ImplausibleFutureDate", LintErrorKind::OutOfRange),
+            ("SuspiciousDateRange", LintErrorKind::OutOfRange),
+            ("TitleMonthMismatch", LintErrorKind::OutOfRange),
+            ("InvalidUrl", LintErrorKind::BadLink),
+            ("UrlContainsTracker", LintErrorKind::BadLink),
+            ("NonHttpsUrl", LintErrorKind::BadLink),
+            ("UpgradedHttpLinks", LintErrorKind::BadLink),
+            ("DisallowedHost", LintErrorKind::BadLink),
+            ("MalformedPlatformUrl", LintErrorKind::BadLink),
+            ("BareDomainUrl", LintErrorKind::BadLink),
+            ("RelativeUrl", LintErrorKind::BadLink),
+            ("OrganizerUrlTooSpecific", LintErrorKind::BadLink),
+            ("DuplicateEventUrl", LintErrorKind::DuplicateLink),
+            ("OrganizerEqualsEventUrl", LintErrorKind::DuplicateLink),
+            ("DuplicateOrganizer", LintErrorKind::DuplicateLink),
+            ("DuplicateTitle", LintErrorKind::DuplicateEvent),
+            ("PossibleRecurringDuplicate", LintErrorKind::DuplicateEvent),
+            ("UnknownRegion", LintErrorKind::Region),
+            ("RegionCasing", LintErrorKind::Region),
+            ("RegionHeaderFormatting", LintErrorKind::Region),
+            ("RegionHeaderWhitespace", LintErrorKind::Region),
+            ("InconsistentGroupName", LintErrorKind::Naming),
+            ("TitleEqualsGroupName", LintErrorKind::Naming),
+            ("NonAsciiApostrophe", LintErrorKind::Naming),
+            ("OrganizerLinkCountMismatch", LintErrorKind::Naming),
+            ("FooterSpacing", LintErrorKind::Formatting),
+            ("MissingCrabEmoji", LintErrorKind::Formatting),
+            ("InconsistentSpacing", LintErrorKind::Formatting),
+            ("NonCanonicalLocation", LintErrorKind::Formatting),
+            ("NonBreakingSpace", LintErrorKind::Formatting),
+            ("DetailIndentation", LintErrorKind::Formatting),
+            ("DateRangeTrailingText", LintErrorKind::Formatting),
+            ("HeaderCasing", LintErrorKind::Formatting),
+            ("ZeroWidthCharacter", LintErrorKind::Formatting),
+            ("LintFailed", LintErrorKind::Failed),
+        ]);
+
+        // one of each lint, no more and no less
+        let names: HashSet<&str> = errors.iter().map(LintError::name).collect();
+        assert_eq!(names.len(), errors.len());
+        assert_eq!(names, kinds.keys().copied().collect());
+
+        for error in &errors {
+            assert_eq!(
+                Some(&error.kind()),
+                kinds.get(error.name()),
+                "{}",
+                error.name()
+            );
+        }
+        assert_eq!(LintErrorKind::OutOfOrder.to_string(), "out-of-order");
+        Ok(())
+    }
+
+    #[test]
+    fn test_severity() {
+        let date = NaiveDate::default();
+        assert_eq!(
+            LintError::EventOutOfOrder {
+                event_date: date,
+                event_location: "Virtual".to_owned(),
+                previous_event_date: date,
+                previous_event_location: "Virtual".to_owned(),
+            }
+            .severity(),
+            Severity::Error
+        );
+        assert_eq!(LintError::UnexpectedEnd.severity(), Severity::Error);
+        assert_eq!(
+            LintError::DuplicateDateRange { line: 1 }.severity(),
+            Severity::Error
+        );
+        assert_eq!(LintError::MissingCrabEmoji.severity(), Severity::Warning);
+        assert_eq!(LintError::NoEventsFound.severity(), Severity::Warning);
+        assert_eq!(
+            LintError::NonHttpsUrl("http://example.com/".to_owned()).severity(),
+            Severity::Warning
+        );
+    }
+
+    #[test]
+    fn test_unknown_region_suggestion() {
+        let near_miss = LintError::UnknownRegion(RegionParseError::new("Ocenia")).to_string();
+        assert!(near_miss.contains("did you mean 'Oceania'?"));
+
+        let unrelated = LintError::UnknownRegion(RegionParseError::new("Pangea")).to_string();
+        assert!(!unrelated.contains("did you mean"));
+    }
+
+    #[test]
+    fn test_footer_spacing() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&build_event_section(None))?;
+        assert!(linter.warnings().is_empty());
+
+        let no_blank_line = build_event_section(None).replace(")\n\nIf you", ")\nIf you");
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&no_blank_line)?;
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::FooterSpacing { blank_lines: 0 }
+        );
+
+        let extra_blank_lines = build_event_section(None).replace(")\n\nIf you", ")\n\n\n\nIf you");
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&extra_blank_lines)?;
+        assert_eq!(linter.warnings()[0].line_num(), 12);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::FooterSpacing { blank_lines: 3 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_range() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.date_range(), None);
+
+        linter.lint(&build_event_section(None))?;
+        assert_eq!(
+            linter.date_range(),
+            Some(("2024-10-23".parse()?, "2024-11-20".parse()?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_inconsistent_group_name() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)\n",
+            "* 2024-11-07 | Berlin, DE | [Rust Berlin DE](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/298633272/)\n",
+            "\n",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(
+            linter.lint(&build_event_section(Some(body))),
+            Err(LintError::LintFailed)
+        );
+
+        let errors = linter.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line_num(), 13);
+        assert_eq!(
+            errors[0].error(),
+            &LintError::InconsistentGroupName {
+                url: "https://www.meetup.com/rust-berlin/".to_owned(),
+                name: "Rust Berlin DE".to_owned(),
+                previous_name: "Rust Berlin".to_owned(),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignore_directive() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-11-07 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/298633272/)\n",
+            "<!-- twir-lint-ignore EventOutOfOrder -->\n",
+            "* 2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)\n",
+            "\n",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&build_event_section(Some(body)))?;
+
+        let without_directive = body.replace("<!-- twir-lint-ignore EventOutOfOrder -->\n", "");
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(
+            linter.lint(&build_event_section(Some(&without_directive))),
+            Err(LintError::LintFailed)
+        );
+        assert_eq!(linter.errors()[0].error().name(), "EventOutOfOrder");
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_http() -> TestResult {
+        let text = build_event_section(None).replace(
+            "(https://www.meetup.com/women-in-rust/)",
+            "(http://www.meetup.com/women-in-rust/)",
+        );
+
+        // without the option we just warn about the link
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::NonHttpsUrl("http://www.meetup.com/women-in-rust/".to_owned())
+        );
+        assert!(linter
+            .edited()
+            .contains("(http://www.meetup.com/women-in-rust/)"));
+
+        // and with it, the link is upgraded
+        let config = LinterConfig {
+            should_edit: true,
+            upgrade_http: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::UpgradedHttpLinks { line: 7 }
+        );
+        assert!(linter
+            .edited()
+            .contains("(https://www.meetup.com/women-in-rust/)"));
+        assert!(!linter.edited().contains("http://"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_http_outside_section() -> TestResult {
+        let text = build_event_section_with_preamble(
+            "see [last week](http://example.com/last-week)\n",
+            None,
+        ) + "[calendar]: http://example.com/calendar\n";
+        let config = LinterConfig {
+            should_edit: true,
+            upgrade_http: true,
+            ..Default::default()
+        };
+
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        assert_eq!(linter.edited(), text);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_on_range_end() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-11-20 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)\n",
+            "\n",
+        );
+        let text = build_event_section(Some(body));
+
+        // the end of the range is inclusive by default
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+
+        let config = LinterConfig {
+            range_exclusive_end: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::EventOutOfDateRange {
+                event_date: "2024-11-20".parse()?,
+                date_range: ("2024-10-23".parse()?, "2024-11-20".parse()?),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_crab_emoji() -> TestResult {
+        let text = build_event_section(None).replace(" 🦀", "");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 4);
+        assert_eq!(linter.warnings()[0].error(), &LintError::MissingCrabEmoji);
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_range_trailing_text() -> TestResult {
+        let text = build_event_section(None).replace(" 🦀", " 🦀.");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 4);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::DateRangeTrailingText {
+                line: 4,
+                trailing: ".".to_owned()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_allowed_hosts() -> TestResult {
+        let config = LinterConfig {
+            allowed_hosts: Some(vec!["meetup.com".to_owned()]),
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config.clone());
+        linter.lint(&build_event_section(None))?;
+
+        let text = build_event_section(None).replace(
+            "https://www.meetup.com/women-in-rust/events/303213835/",
+            "https://sketchy.example/women-in-rust/",
+        );
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::DisallowedHost("sketchy.example".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bare_domain_url() {
+        let text = build_event_section(None).replace(
+            "https://www.meetup.com/women-in-rust/events/303213835/",
+            "https://www.meetup.com/",
+        );
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::BareDomainUrl {
+                line: 8,
+                url: "https://www.meetup.com/".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_apostrophe() -> TestResult {
+        let text = SectionBuilder::new()
+            .region("Europe")
+            .event(
+                "2024-10-24 | Stockholm, SE | [Ferris' Fika](https://www.meetup.com/ferris-fika/)",
+                "[**Fika and Rust**](https://www.meetup.com/ferris-fika/events/303000001/)",
+            )
+            .event(
+                "2024-10-31 | Stockholm, SE | [Ferris\u{2019} Fika](https://www.meetup.com/ferris-fika/)",
+                "[**More Fika and Rust**](https://www.meetup.com/ferris-fika/events/303000002/)",
+            )
+            .build();
+
+        // the names only differ by apostrophe, so they're the same group
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::NonAsciiApostrophe { line: 8 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrapped_title() -> TestResult {
+        let text = build_event_section(None).replace(
+            "Hackathon Showcase: Final",
+            "Hackathon Showcase:\n    Final",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        let listings = linter.events().get("Virtual").ok_or("missing region")?;
+        assert_eq!(
+            listings[0].events()[0]
+                .iter()
+                .next()
+                .map(|event| event.label()),
+            Some("**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**")
+        );
+
+        let text = build_event_section(None).replace(
+            "/events/303213835/)\n",
+            "/events/303213835/)\nFinal Projects and Presentations\n",
+        );
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::AmbiguousContinuation { line: 9 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncated_section() {
+        // cut the draft off partway through the Virtual region, with no footer
+        let text = build_event_section(None);
+        let truncated = &text[..text.find("    * [**Part 4").unwrap()];
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(truncated), Err(LintError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_section_unreadable() {
+        let text = build_event_section(None);
+
+        let mut linter = EventSectionLinter::default();
+        let result = linter.lint(&text);
+        assert!(!linter.section_unreadable(&result));
+
+        // lint errors still leave events to count
+        let bad_date = text.replace("2024-10-24 |", "2024-13-24 |");
+        let mut linter = EventSectionLinter::default();
+        let result = linter.lint(&bad_date);
+        assert!(result.is_err());
+        assert!(!linter.section_unreadable(&result));
+
+        // as does stopping at the error limit
+        let config = LinterConfig {
+            error_limit: 1,
+            ..LinterConfig::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        let result = linter.lint(&bad_date);
+        assert_eq!(result, Err(LintError::LintFailed));
+        assert!(linter.reached_error_limit());
+        assert!(!linter.section_unreadable(&result));
+
+        let mut linter = EventSectionLinter::default();
+        let result = linter.lint(&text[..text.find("    * [**Part 4").unwrap()]);
+        assert!(linter.section_unreadable(&result));
+
+        let mut linter = EventSectionLinter::default();
+        let result = linter.lint("# This Week in Rust\n");
+        assert!(linter.section_unreadable(&result));
+    }
+
+    #[test]
+    fn test_sort_organizers() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/) + [OpenTechSchool Berlin](https://berline.rs/)\n",
+            "    * [**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)\n",
+            "\n",
+        );
+        let config = LinterConfig {
+            should_edit: true,
+            sort_organizers: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&build_event_section(Some(body)))?;
+
+        assert!(linter.edited().contains("* 2024-10-31 | Berlin, DE | [OpenTechSchool Berlin](https://berline.rs/) + [Rust Berlin](https://www.meetup.com/rust-berlin/)\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_events_keeps_organizer_order() -> TestResult {
+        let text = SectionBuilder::new()
+            .region("Europe")
+            .event(
+                "2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/) + [OpenTechSchool Berlin](https://berline.rs/)",
+                "[**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)",
+            )
+            .event(
+                "2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)",
+                "[**Hack Night**](https://www.meetup.com/rust-aarhus/events/303479865/)",
+            )
+            .build();
+        let config = LinterConfig {
+            should_edit: true,
+            sort_events: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+
+        let expected = SectionBuilder::new()
+            .region("Europe")
+            .event(
+                "2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)",
+                "[**Hack Night**](https://www.meetup.com/rust-aarhus/events/303479865/)",
+            )
+            .event(
+                "2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/) + [OpenTechSchool Berlin](https://berline.rs/)",
+                "[**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)",
+            )
+            .build();
+        assert_eq!(linter.edited(), expected);
+
+        // without sorting, the same draft fails
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_events_only_in_section() -> TestResult {
+        // lines outside of the section that look like listings stay where they are
+        let outside = concat!(
+            "* 2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "* 2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)\n",
+        );
+        let text = SectionBuilder::new()
+            .preamble(outside)
+            .region("Europe")
+            .event(
+                "2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+                "[**Rust and Tell**](https://www.meetup.com/rust-berlin/events/300820289/)",
+            )
+            .event(
+                "2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)",
+                "[**Hack Night**](https://www.meetup.com/rust-aarhus/events/303479865/)",
+            )
+            .build()
+            + outside;
+        let config = LinterConfig {
+            should_edit: true,
+            sort_events: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+
+        let edited = linter.edited();
+        assert!(edited.starts_with(outside));
+        assert!(edited.ends_with(outside));
+        let aarhus =
+            edited.find("Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)\n    *");
+        let berlin =
+            edited.find("Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n    *");
+        assert!(aarhus < berlin);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flag_recurring() -> TestResult {
+        let text = SectionBuilder::new()
+            .region("Europe")
+            .event(
+                "2024-10-24 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+                "[**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/298633271/)",
+            )
+            .event(
+                "2024-10-31 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+                "[** Rust Hack and Learn **](https://www.meetup.com/rust-berlin/events/298633272/)",
+            )
+            .build();
+
+        // off by default, we only notice the titles are the same
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].error().name(), "DuplicateTitle");
+
+        let config = LinterConfig {
+            flag_recurring: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::PossibleRecurringDuplicate {
+                line: 9,
+                previous_line: 7
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_check_dates() -> TestResult {
+        let text = build_event_section(None).replace(
+            "Part 4 of 4 - Hackathon Showcase",
+            "November Meetup - Hackathon Showcase",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+
+        let config = LinterConfig {
+            cross_check_dates: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config.clone());
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::TitleMonthMismatch {
+                line: 8,
+                month: "November".to_owned()
+            }
+        );
+
+        // the event is in October, so this one is fine
+        let text = build_event_section(None).replace(
+            "Part 4 of 4 - Hackathon Showcase",
+            "October Meetup - Hackathon Showcase",
+        );
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_check_dates_range() -> TestResult {
+        // a range over three months includes the one in the middle
+        let text = SectionBuilder::new()
+            .region("Virtual")
+            .event(
+                "2024-10-24 - 2024-12-05 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "[**November Hackathon**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .build();
+        let config = LinterConfig {
+            cross_check_dates: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_title_months() {
+        // "May" and "March" are ordinary words unless they're next to a day
+        assert_eq!(
+            EventSectionLinter::title_months("**Why You May Want Rust**"),
+            vec![]
+        );
+        assert_eq!(
+            EventSectionLinter::title_months("**May the Borrow Checker Be With You**"),
+            vec![]
+        );
+        assert_eq!(
+            EventSectionLinter::title_months("**Rust Hack Night, May 5th**"),
+            vec![Month::May]
+        );
+        assert_eq!(
+            EventSectionLinter::title_months("**Meetup on 12 March**"),
+            vec![Month::March]
+        );
+        // other months always count, wherever they are
+        assert_eq!(
+            EventSectionLinter::title_months("**November Meetup**"),
+            vec![Month::November]
+        );
+        assert_eq!(
+            EventSectionLinter::title_months("**Rustaceans in november**"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_no_organizers() -> TestResult {
+        let mut linter = EventSectionLinter {
+            line_num: 7,
+            current_region: Some("Virtual".to_owned()),
+            linter_state: LinterState::ExpectingEventDateLocationGroupLink,
+            ..Default::default()
+        };
+
+        let date_location =
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)"
+                .parse::<EventLineType>()
+                .map(|line_type| match line_type {
+                    EventLineType::EventDateLocationGroup(overview) => {
+                        Some(overview.date_location().clone())
+                    }
+                    _ => None,
+                })?
+                .ok_or("not an overview")?;
+        let overview = EventOverview::new(date_location, Vec::new().into());
+
+        assert_eq!(
+            linter.handle_expecting_event_date_location_group_link(
+                EventLineType::EventDateLocationGroup(overview)
+            ),
+            Err(LintError::NoOrganizers { line: 7 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_range_before_section() {
+        let text = build_event_section_with_preamble(
+            "some pre events section text\nRusty Events between 2024-10-23 - 2024-11-20 🦀\n",
+            None,
+        )
+        .replace(
+            "## Upcoming Events\n\nRusty Events between 2024-10-23 - 2024-11-20 🦀\n",
+            "## Upcoming Events\n",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        // only the misplaced line is reported, the events are still checked against its range
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::DateRangeBeforeSection { line: 2 }
+        );
+        assert!(linter.date_range().is_some());
+    }
+
+    #[test]
+    fn test_organizers() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(include_str!("../test/570.md"))?;
+        let organizers = linter.organizers();
+
+        let women_in_rust: Vec<&(&Url, &str)> = organizers
+            .iter()
+            .filter(|(url, _)| url.as_str() == "https://www.meetup.com/women-in-rust/")
+            .collect();
+        assert_eq!(women_in_rust.len(), 1);
+        assert_eq!(women_in_rust[0].1, "Women in Rust");
+        assert!(organizers.is_sorted_by_key(|(url, _)| url.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_inconsistent_spacing() -> TestResult {
+        let text = build_event_section(None)
+            .replace("* 2024-10-24 | Virtual |", "* 2024-10-24  | Virtual  |  ");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 7);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::InconsistentSpacing
+        );
+
+        // we still parse the line as usual
+        let listings = linter.events().filter_region("Virtual")?;
+        assert!(listings
+            .to_string()
+            .contains("* 2024-10-24 | Virtual | [Women in Rust]"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_organizer_equals_event_url() -> TestResult {
+        let text = build_event_section(None).replace(
+            "(https://www.meetup.com/women-in-rust/events/303213835/)",
+            "(https://meetup.com/women-in-rust/)",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::OrganizerEqualsEventUrl { line: 8 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_organizer_equals_extra_event_url() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "    * [**Rust Talks**](https://www.meetup.com/rust-berlin/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter
+            .warnings()
+            .iter()
+            .any(|warning| warning.error() == &LintError::OrganizerEqualsEventUrl { line: 13 }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_title_equals_group_name() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Berlin**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 12);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::TitleEqualsGroupName("Rust Berlin".to_owned())
+        );
+
+        let text = build_event_section(Some(
+            &body.replace("[**Rust Berlin**]", "[**Rust Hack and Learn**]"),
+        ));
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_online_location() -> TestResult {
+        let text = build_event_section(None).replace("| Virtual |", "| Online |");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 7);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::NonCanonicalLocation {
+                found: "Online".to_owned(),
+                expected: "Virtual".to_owned()
+            }
+        );
+
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text.replace("| Online |", "| online (Berlin, DE) |"))?;
+        assert!(linter
+            .edited()
+            .contains("* 2024-10-24 | Virtual (Berlin, DE) | [Women in Rust]"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unlimited_errors() -> TestResult {
+        // every one of these is out of our date range
+        let body: String = (1..=30)
+            .map(|day| {
+                format!(
+                    "* 2024-12-{:02} | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n    * [**Meetup {}**](https://www.meetup.com/women-in-rust/events/3032138{:02}/)\n",
+                    day, day, day
+                )
+            })
+            .collect();
+        let text = build_event_section(Some(&format!("### Europe\n{}\n", body)));
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 20);
+        assert!(linter.reached_error_limit());
+
+        let config = LinterConfig {
+            error_limit: 0,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 30);
+        assert!(!linter.reached_error_limit());
+        assert!(linter
+            .errors()
+            .iter()
+            .all(|e| matches!(e.error(), LintError::EventOutOfDateRange { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_suspicious_date_range() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&build_event_section(None))?;
+        assert!(linter.warnings().is_empty());
+
+        // year typo in the end date, our event is still within this (huge) range
+        let text = build_event_section(None).replace("- 2024-11-20", "- 2025-11-20");
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 4);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::SuspiciousDateRange {
+                start: NaiveDate::from_ymd_opt(2024, 10, 23).ok_or("bad date")?,
+                end: NaiveDate::from_ymd_opt(2025, 11, 20).ok_or("bad date")?,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_casing() -> TestResult {
+        let text = build_event_section(None).replace("### Virtual", "### virtual");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 6);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::RegionCasing {
+                found: "virtual".to_owned(),
+                expected: "Virtual".to_owned()
+            }
+        );
+        assert_eq!(
+            linter.events().iter().next().map(|(region, _)| region),
+            Some("Virtual")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_with_visitor() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-12-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "* 2024-11-12  | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Talks**](https://www.meetup.com/rust-berlin/events/301234568/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut seen = Vec::new();
+        let mut linter = EventSectionLinter::default();
+        let result = linter.lint_with_visitor(&text, |diagnostic| {
+            seen.push((diagnostic.line_num(), diagnostic.error().name()))
+        });
+
+        assert_eq!(result, Err(LintError::LintFailed));
+        assert_eq!(
+            seen,
+            vec![(11, "EventOutOfDateRange"), (13, "InconsistentSpacing")]
+        );
+        assert!(linter.errors().is_empty());
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_organizer_link_count_mismatch() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [OpenTechSchool Berlin](https://berline.rs/) + [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 12);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::OrganizerLinkCountMismatch {
+                organizers: 2,
+                links: 1
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_structure_only() -> TestResult {
+        let text = build_event_section(None)
+            .replace("Rusty Events between 2024-10-23 - 2024-11-20 🦀\n\n", "");
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+
+        let config = LinterConfig {
+            structure_only: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert!(linter.errors().is_empty());
+        assert_eq!(linter.date_range(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_event_lines() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "    * [**Rust Talks**](https://www.meetup.com/rust-berlin/events/301234568/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter.errors().is_empty());
+
+        let europe = linter.events().filter_region("Europe")?;
+        let (_, listings) = europe.iter().next().ok_or("no listings")?;
+        assert_eq!(listings.len(), 1);
+        assert_eq!(listings[0].events().len(), 2);
+        assert_eq!(format!("{}\n", listings[0]), body[11..body.len() - 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_event_lines_after_error() -> TestResult {
+        let text = SectionBuilder::new()
+            .region("Europe")
+            .event(
+                "2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)",
+                "[**Hack Night**](https://www.meetup.com/rust-aarhus/events/303479865/)",
+            )
+            .event(
+                "2024-10-24 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+                "[**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/298633271/)",
+            )
+            .build()
+            .replace(
+                "events/298633271/)\n",
+                "events/298633271/)\n    * [**Second Berlin Thing**](https://www.meetup.com/rust-berlin/events/298633272/)\n",
+            );
+
+        let mut linter = EventSectionLinter::default();
+        assert!(linter.lint(&text).is_err());
+        assert!(matches!(
+            linter.errors()[..],
+            [LintDiagnostic {
+                line_num: 8,
+                error: LintError::EventOutOfOrder { .. },
+                ..
+            }]
+        ));
+
+        // the Berlin event lines don't belong to the Aarhus listing
+        let listings = linter.events().get("Europe").ok_or("no listings")?;
+        assert_eq!(listings.len(), 1);
+        assert_eq!(listings[0].events().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_event_lines_checked() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "    * [**Rust Berlin**](https://www.meetup.com/rust-berlin/events/301234568/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter
+            .warnings()
+            .iter()
+            .any(|warning| warning.line_num() == 13
+                && matches!(warning.error(), LintError::TitleEqualsGroupName(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_organizer_url_too_specific() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(linter.errors()[0].line_num(), 11);
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::OrganizerUrlTooSpecific(
+                "https://www.meetup.com/rust-berlin/events/301234567/".to_owned()
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_resync_on_region() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "Rust Hack and Learn, see the meetup page for details\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "* 2024-10-30 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "\n",
+            "### North America\n",
+            "* 2024-10-29 | New York, NY, US | [Rust NYC](https://www.meetup.com/rust-nyc/)\n",
+            "    * [**Rust NYC Monthly Meetup**](https://www.meetup.com/rust-nyc/events/301234569/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert!(linter.errors().len() > 1);
+
+        let config = LinterConfig {
+            resync_on_region: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(linter.errors()[0].line_num(), 12);
+
+        let north_america = linter.events().filter_region("North America")?;
+        let (_, listings) = north_america.iter().next().ok_or("no listings")?;
+        assert_eq!(listings.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_title() -> TestResult {
+        let body = concat!(
+            "### Europe\n",
+            "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+            "* 2024-10-30 | London, UK | [Rust London User Group](https://www.meetup.com/rust-london-user-group/)\n",
+            "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-london-user-group/events/301234568/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 14);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::DuplicateTitle {
+                title: "Rust Hack and Learn".to_owned(),
+                previous_line: 12
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_day_event() -> TestResult {
+        let body = concat!(
+            "### North America\n",
+            "* 2024-11-06 - 2024-11-08 | Montréal, QC, CA | [Rust Montréal](https://www.meetup.com/rust-montreal/)\n",
+            "    * [**RustConf Montréal**](https://www.meetup.com/rust-montreal/events/301234567/)\n",
+            "* 2024-11-07 | Toronto, ON, CA | [Rust Toronto](https://www.meetup.com/rust-toronto/)\n",
+            "    * [**Rust Toronto Meetup**](https://www.meetup.com/rust-toronto/events/301234568/)\n",
+            "\n"
+        );
+        let text = build_event_section(Some(body));
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_implausible_future_date() -> TestResult {
+        // our date range ends 2024-11-20
+        for (event_date, implausible) in [("2025-11-20", false), ("2025-11-21", true)] {
+            let text = build_event_section(None)
+                .replace("2024-10-24 | Virtual", &format!("{} | Virtual", event_date));
+
+            let mut linter = EventSectionLinter::default();
+            assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+            assert_eq!(linter.errors()[0].error().name(), "EventOutOfDateRange");
+
+            let expected = LintError::ImplausibleFutureDate {
+                event_date: event_date.parse()?,
+            };
+            let warnings: Vec<&LintError> = linter
+                .warnings()
+                .iter()
+                .map(|warning| warning.error())
+                .collect();
+            if implausible {
+                assert_eq!(warnings, vec![&expected]);
+            } else {
+                assert!(warnings.is_empty(), "{}", event_date);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_organizer() -> TestResult {
+        let text = build_event_section(None).replace(
+            "[Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+            "[Women in Rust](https://www.meetup.com/women-in-rust/) + [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+        );
+
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 7);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::DuplicateOrganizer("Women in Rust".to_owned())
+        );
+        assert_eq!(linter.edited(), build_event_section(None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_not_first() -> TestResult {
+        let text = build_event_section(None).replace(
+            "### Virtual\n",
+            concat!(
+                "### Europe\n",
+                "* 2024-10-29 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)\n",
+                "    * [**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/301234567/)\n",
+                "\n",
+                "### Virtual\n",
+            ),
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 10);
+        assert_eq!(linter.warnings()[0].error(), &LintError::VirtualNotFirst);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_events_found() -> TestResult {
+        let text = concat!(
+            "## Upcoming Events\n",
+            "\n",
+            "Rusty Events between 2024-10-23 - 2024-11-20 🦀\n",
+            "\n",
+            "If you are running a Rust event please add it to the [calendar] to get\n",
+            "it mentioned here. Please remember to add a link to the event too.\n",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].error(), &LintError::NoEventsFound);
+
+        let config = LinterConfig {
+            require_events: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(linter.errors()[0].error(), &LintError::NoEventsFound);
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&build_event_section(None))?;
+        assert!(linter.warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_header_formatting() -> TestResult {
+        for header in ["## Virtual", "###Virtual", "####  Virtual"] {
+            let text = build_event_section(None).replace("### Virtual", header);
+
+            let mut linter = EventSectionLinter::default();
+            linter.lint(&text)?;
+            assert_eq!(linter.warnings().len(), 1, "{}", header);
+            assert_eq!(linter.warnings()[0].line_num(), 6);
+            assert_eq!(
+                linter.warnings()[0].error(),
+                &LintError::RegionHeaderFormatting(header.to_owned())
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_breaking_space() -> TestResult {
+        let text = build_event_section(None).replace(
+            "* 2024-10-24 | Virtual | ",
+            "* 2024-10-24\u{a0}|\u{a0}Virtual\u{a0}| ",
+        );
+
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].line_num(), 7);
+        assert_eq!(linter.warnings()[0].error(), &LintError::NonBreakingSpace);
+        assert_eq!(linter.edited(), build_event_section(None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_width_character() -> TestResult {
+        let text =
+            build_event_section(None).replace("Hackathon Showcase", "Hackathon\u{200b} Showcase");
+
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::ZeroWidthCharacter { line: 8 }
+        );
+        assert_eq!(linter.edited(), build_event_section(None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_dates_in_range() -> TestResult {
+        let text =
+            build_event_section(None).replace("* 2024-10-24 |", "* 2024-10-24, 2024-10-31 |");
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+
+        // the second date is after our 2024-11-20 range end
+        let text =
+            build_event_section(None).replace("* 2024-10-24 |", "* 2024-10-24, 2024-11-21 |");
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::EventOutOfDateRange {
+                event_date: "2024-11-21".parse()?,
+                date_range: ("2024-10-23".parse()?, "2024-11-20".parse()?),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_detail_indentation() -> TestResult {
+        for spaces in [2, 6] {
+            let text = build_event_section(None)
+                .replace("    * [**", &format!("{}* [**", " ".repeat(spaces)));
+
+            let mut linter = EventSectionLinter::default();
+            linter.lint(&text)?;
+            assert_eq!(linter.warnings().len(), 1, "{} spaces", spaces);
+            assert_eq!(linter.warnings()[0].line_num(), 8);
+            assert_eq!(
+                linter.warnings()[0].error(),
+                &LintError::DetailIndentation { line: 8, spaces }
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_before_region() -> TestResult {
+        let text = build_event_section(None).replace("### Virtual\n", "");
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors()[0].line_num(), 6);
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::EventBeforeRegion { line: 6 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_header_whitespace() -> TestResult {
+        let text = build_event_section(None).replace("### Virtual", "### North  America");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::RegionHeaderWhitespace { line: 6 }
+        );
+        assert_eq!(
+            linter
+                .events()
+                .filter_region("North America")?
+                .iter()
+                .count(),
+            1
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_cache() -> TestResult {
+        let sample = include_str!("../test/570.md");
+        let mut linter = EventSectionLinter::default();
+        linter.lint(sample)?;
+        assert!(linter.lines_parsed() > 0);
+
+        let mut linter =
+            EventSectionLinter::with_cache(LinterConfig::default(), linter.into_cache());
+        linter.lint(sample)?;
+        assert_eq!(linter.lines_parsed(), 0);
+
+        // only the changed line is parsed again
+        let changed = sample.replace("[Rust ATX]", "[Rust Austin]");
+        let mut linter =
+            EventSectionLinter::with_cache(LinterConfig::default(), linter.into_cache());
+        linter.lint(&changed)?;
+        assert_eq!(linter.lines_parsed(), 1);
+
+        // the line from before the edit was dropped, so changing it back parses it again
+        let mut linter =
+            EventSectionLinter::with_cache(LinterConfig::default(), linter.into_cache());
+        linter.lint(sample)?;
+        assert_eq!(linter.lines_parsed(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_event_urls() -> TestResult {
+        let event = concat!(
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+            "    * [**Rust Talks**](https://www.meetup.com/women-in-rust/events/303213835/)\n",
+        );
+        // the event from build_event_section is on line 8, so these repeat its link on lines 10 and 12
+        let text = build_event_section(None).replacen(
+            "303213835/)\n",
+            &format!("303213835/)\n{}", event.repeat(2)),
+            1,
+        );
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        let url = Url::parse("https://www.meetup.com/women-in-rust/events/303213835/")?;
+        assert_eq!(
+            linter.duplicate_event_urls(),
+            vec![(url.clone(), vec![8, 10, 12])]
+        );
+
+        let duplicates: Vec<&LintDiagnostic> = linter
+            .warnings()
+            .iter()
+            .filter(|warning| warning.error().name() == "DuplicateEventUrl")
+            .collect();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].line_num(), 10);
+        assert_eq!(
+            duplicates[0].error(),
+            &LintError::DuplicateEventUrl {
+                url: url.to_string(),
+                lines: vec![8, 10, 12],
+            }
+        );
+
+        // the warning is on the first repeat, so an ignore directive there skips it
+        let text = text.replacen(
+            "    * [**Rust Talks**]",
+            "<!-- twir-lint-ignore DuplicateEventUrl -->\n    * [**Rust Talks**]",
+            1,
+        );
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.duplicate_event_urls()[0].1, vec![8, 11, 13]);
+        assert!(linter
+            .warnings()
+            .iter()
+            .all(|warning| warning.error().name() != "DuplicateEventUrl"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_date_range() -> TestResult {
+        let text = build_event_section(None).replace(
+            "### Virtual\n",
+            "Rusty Events between 2024-10-30 - 2024-11-27 🦀\n\n### Virtual\n",
+        );
+
+        let mut linter = EventSectionLinter::default();
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(
+            linter.errors()[0].error(),
+            &LintError::DuplicateDateRange { line: 6 }
+        );
+        assert_eq!(
+            linter.date_range(),
+            Some(("2024-10-23".parse()?, "2024-11-20".parse()?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_deny_warnings() -> TestResult {
+        let text = build_event_section(None).replace(" 🦀", "");
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(linter.warnings()[0].severity(), Severity::Warning);
+
+        let config = LinterConfig {
+            deny_warnings: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        assert_eq!(linter.lint(&text), Err(LintError::LintFailed));
+        assert!(linter.warnings().is_empty());
+        assert_eq!(linter.errors().len(), 1);
+        assert_eq!(linter.errors()[0].line_num(), 4);
+        assert_eq!(linter.errors()[0].error(), &LintError::MissingCrabEmoji);
+        assert_eq!(linter.errors()[0].severity(), Severity::Error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_header_casing() -> TestResult {
+        let text = build_event_section(None).replace("## Upcoming Events", "## Upcoming events");
+
+        let config = LinterConfig {
+            should_edit: true,
+            ..Default::default()
+        };
+        let mut linter = EventSectionLinter::new(config);
+        linter.lint(&text)?;
+        assert_eq!(linter.warnings().len(), 1);
+        assert_eq!(
+            linter.warnings()[0].error(),
+            &LintError::HeaderCasing { line: 2 }
+        );
+        assert_eq!(linter.events().counts(), vec![("Virtual", 1)]);
+        assert_eq!(linter.edited(), build_event_section(None));
+        Ok(())
+    }
 }
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use crate::lint::{EventSectionLinter, LintDiagnostic, Severity};
+
+/// Diagnostic severities from the language server protocol
+const LSP_ERROR: u8 = 1;
+const LSP_WARNING: u8 = 2;
+
+/// Renders the linter's errors and warnings as a JSON array of language server protocol `Diagnostic`s, so editors can
+/// show them inline. Each diagnostic covers its whole line
+pub fn to_lsp_json(md: &str, linter: &EventSectionLinter) -> String {
+    let lines: Vec<&str> = md.lines().collect();
+    let mut diagnostics: Vec<&LintDiagnostic> =
+        linter.errors().iter().chain(linter.warnings()).collect();
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line_num());
+
+    let diagnostics: Vec<String> = diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            let severity = match diagnostic.severity() {
+                Severity::Error => LSP_ERROR,
+                Severity::Warning => LSP_WARNING,
+            };
+            // lsp lines are 0-based, and characters are counted in utf-16 code units
+            let line = diagnostic.line_num().saturating_sub(1);
+            let line_len = lines
+                .get(line)
+                .map_or(0, |text| text.encode_utf16().count());
+
+            format!(
+                r#"{{"range":{{"start":{{"line":{},"character":0}},"end":{{"line":{},"character":{}}}}},"severity":{},"source":"twir-events-lint","code":{},"message":{}}}"#,
+                line,
+                line,
+                line_len,
+                severity,
+                json_string(diagnostic.error().name()),
+                json_string(&diagnostic.error().to_string())
+            )
+        })
+        .collect();
+
+    format!("[{}]", diagnostics.join(","))
+}
+
+/// Quotes and escapes a string for JSON
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // writing to a string can't fail
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    #[test]
+    fn test_to_lsp_json() {
+        // the date range is on line 155 of the sample, or line 154 counting from 0
+        let md = SAMPLE.replace("2024-11-20 🦀", "2024-11-20");
+        let mut linter = EventSectionLinter::default();
+        let _ = linter.lint(&md);
+
+        let json = to_lsp_json(&md, &linter);
+        assert!(json.starts_with("[{\"range\":"));
+        assert!(json.contains(
+            r#"{"range":{"start":{"line":154,"character":0},"end":{"line":154,"character":44}},"severity":2,"source":"twir-events-lint","code":"MissingCrabEmoji","#
+        ));
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(
+            json_string("Found \"Pangea\"\nExpected \\"),
+            r#""Found \"Pangea\"\nExpected \\""#
+        );
+    }
+}
@@ -1,8 +1,24 @@
-use std::{error::Error, fs};
+use std::{
+    error::Error,
+    fs, io, mem,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, SystemTime},
+};
 
 use clap::Parser;
-use log::{error, info};
-use twir_events_lint::{args::Args, lint::EventSectionLinter};
+use log::{error, info, warn};
+use twir_events_lint::{
+    args::{Args, OutputFormat},
+    diff::git_changed_lines,
+    draft::{fetch_draft, read_draft},
+    lint::{EventSectionLinter, LineCache},
+    lsp::to_lsp_json,
+    rss::to_rss,
+};
+
+/// How often we check if the file changed in watch mode
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
 
 fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
@@ -15,14 +31,156 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     simple_logger::init_with_level(log_level).expect("Failed to init logger!");
 
-    info!("Reading file '{}'", args.file().display());
-    let md = fs::read_to_string(args.file())?;
+    let md = match (args.url(), args.file()) {
+        (Some(url), _) => {
+            info!("Fetching '{}'", url);
+            fetch_draft(url)?
+        }
+        (None, Some(file)) => {
+            info!("Reading file '{}'", file.display());
+            read_draft(file)?
+        }
+        // clap requires one or the other
+        (None, None) => unreachable!(),
+    };
+
+    if args.count_only() {
+        let mut event_linter = EventSectionLinter::new(args.linter_config());
+        let result = event_linter.lint(&md);
+        if event_linter.reached_error_limit() {
+            println!(
+                "{} - stopped at the error limit, so this may be missing events",
+                event_linter.events().count_summary()
+            );
+        } else {
+            println!("{}", event_linter.events().count_summary());
+        }
+        // a truncated or missing events section can't be counted
+        if event_linter.section_unreadable(&result) {
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let (mut line_cache, passed) = lint(&args, &md, LineCache::default())?;
+
+    if let (true, Some(file)) = (args.watch(), args.file()) {
+        info!("Watching '{}' for changes", file.display());
+        let mut modified = modified_times(file)?;
+        loop {
+            thread::sleep(WATCH_INTERVAL);
+            // editors that save by replacing the file can briefly remove it, so keep watching through errors
+            let last_modified = match modified_times(file) {
+                Ok(last_modified) => last_modified,
+                Err(e) => {
+                    warn!("Couldn't check '{}' for changes: {}", file.display(), e);
+                    continue;
+                }
+            };
+            if last_modified == modified {
+                continue;
+            }
+
+            info!("'{}' changed, linting again", file.display());
+            let md = match read_draft(file) {
+                Ok(md) => md,
+                Err(e) => {
+                    warn!("Couldn't read '{}': {}", file.display(), e);
+                    continue;
+                }
+            };
+            modified = last_modified;
+            match lint(&args, &md, mem::take(&mut line_cache)) {
+                Ok((cache, _)) => line_cache = cache,
+                Err(e) => error!("{}", e),
+            }
+        }
+    }
+
+    if !passed {
+        process::exit(1);
+    }
 
-    let mut event_linter = EventSectionLinter::new(args.edit(), args.error_limit());
-    match event_linter.lint(&md) {
+    Ok(())
+}
+
+/// When each file we read the draft from was last modified. For a directory of region files that's each file in it,
+/// since editing a file in place doesn't always update its directory's time
+fn modified_times(path: &Path) -> io::Result<Vec<(PathBuf, SystemTime)>> {
+    if !path.is_dir() {
+        return Ok(vec![(path.to_owned(), fs::metadata(path)?.modified()?)]);
+    }
+
+    let mut times = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        times.push((entry.path(), entry.metadata()?.modified()?));
+    }
+    times.sort();
+    Ok(times)
+}
+
+/// Lints the draft and prints any output we were asked for, returning the lines we parsed for next time and whether
+/// the lint passed
+fn lint(args: &Args, md: &str, line_cache: LineCache) -> Result<(LineCache, bool), Box<dyn Error>> {
+    let mut config = args.linter_config();
+    if let (Some(git_ref), Some(file)) = (args.since(), args.file()) {
+        config.changed_lines = Some(git_changed_lines(file, git_ref)?);
+    }
+
+    let mut event_linter = EventSectionLinter::with_cache(config, line_cache);
+    let result = event_linter.lint(md);
+    match &result {
         Ok(_) => info!("LGTM!"),
         Err(e) => error!("{}", e),
     }
 
-    Ok(())
+    if args.edit() {
+        print!("{}", event_linter.edited());
+    }
+
+    if let Some(region) = args.region() {
+        print!("{}", event_linter.events().filter_region(region)?);
+    }
+
+    if args.group_by_week() {
+        print!("{}", event_linter.events().by_week());
+    }
+
+    if args.group_by_date() {
+        print!("{}", event_linter.events().by_date());
+    }
+
+    if args.list_first_day() {
+        if let Some((start, _)) = event_linter.date_range() {
+            info!("Events on the first day of the newsletter, {}:", start);
+            print!("{}", event_linter.events().on_date(start));
+        }
+    }
+
+    if args.region_date_spans() {
+        if let Some(range) = event_linter.date_range() {
+            print!(
+                "{}",
+                event_linter
+                    .events()
+                    .date_span_report(range, args.range_exclusive_end())
+            );
+        }
+    }
+
+    if args.list_organizers() {
+        for (url, name) in event_linter.organizers() {
+            println!("{} - {}", name, url);
+        }
+    }
+
+    match args.format() {
+        Some(OutputFormat::Table) => print!("{}", event_linter.events().to_table()),
+        Some(OutputFormat::Lsp) => println!("{}", to_lsp_json(md, &event_linter)),
+        Some(OutputFormat::Rss) => print!("{}", to_rss(event_linter.events())),
+        None => (),
+    }
+
+    Ok((event_linter.into_cache(), result.is_ok()))
 }
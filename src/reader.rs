@@ -0,0 +1,253 @@
+use std::{
+    borrow::Cow,
+    iter::{Enumerate, Peekable},
+    str::Lines,
+};
+
+use crate::{
+    constants::{
+        END_EVENTS_SECTION, EVENTS_DATE_RANGE_HINT, EVENT_NAME_HINT, IGNORE_DIRECTIVE_START,
+        START_EVENTS_SECTION,
+    },
+    event_line_types::EventLineType,
+};
+
+/// A line of a draft, with where it is in the file. An event title wrapped onto more lines is joined back into a
+/// single line, numbered after its first line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    num: usize,
+    text: Cow<'a, str>,
+    start_offset: usize,
+    end_offset: usize,
+    /// Whether this looks like the rest of a wrapped title, but follows a complete event line
+    ambiguous_continuation: bool,
+}
+
+impl<'a> Line<'a> {
+    /// Line number, starting from 1
+    pub fn num(&self) -> usize {
+        self.num
+    }
+
+    /// The line's text, without its line ending
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Byte offset of the start of the line in the file
+    pub fn start_offset(&self) -> usize {
+        self.start_offset
+    }
+
+    /// Byte offset just past the end of the line's text in the file, not including its line ending
+    pub fn end_offset(&self) -> usize {
+        self.end_offset
+    }
+
+    /// Whether this line could be the rest of a wrapped event title, but the event line before it already looks
+    /// complete, so we can't tell if it belongs to it
+    pub fn is_ambiguous_continuation(&self) -> bool {
+        self.ambiguous_continuation
+    }
+}
+
+/// Reads a draft line by line, along with line numbers that match the file's. A bounded reader stops after a number of
+/// regions in the events section, for tools that only need the start of the section, e.g. the date range
+#[derive(Debug)]
+pub struct Reader<'a> {
+    contents: &'a str,
+    lines: Peekable<Enumerate<Lines<'a>>>,
+    /// Number of regions to read before stopping, if any
+    max_regions: Option<usize>,
+    /// Region headers we've read so far
+    regions: usize,
+    /// Whether we've reached the events section, region headers are only counted within it
+    in_events_section: bool,
+    /// Whether the last line we read was an event line that looked complete, so a continuation after it is ambiguous
+    after_complete_event: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(contents: &'a str) -> Self {
+        Self {
+            contents,
+            lines: contents.lines().enumerate().peekable(),
+            max_regions: None,
+            regions: 0,
+            in_events_section: false,
+            after_complete_event: false,
+        }
+    }
+
+    /// A reader that stops before the region header after the first `max_regions` regions
+    pub fn new_bounded(contents: &'a str, max_regions: usize) -> Self {
+        Self {
+            max_regions: Some(max_regions),
+            ..Self::new(contents)
+        }
+    }
+
+    /// Byte offset of a line in our contents, lines are slices of our contents so we can find where they start from
+    /// where they point to
+    fn offset_of(&self, line: &str) -> usize {
+        line.as_ptr() as usize - self.contents.as_ptr() as usize
+    }
+
+    /// Event lines start with "* [**" indented under their overview
+    fn is_event_name(line: &str) -> bool {
+        line.starts_with(' ') && line.trim_start().starts_with(EVENT_NAME_HINT.trim_start())
+    }
+
+    /// Whether a line could be the rest of a wrapped event title, i.e. it isn't any of the lines we expect in a region
+    fn is_continuation(line: &str) -> bool {
+        let trimmed = line.trim_start();
+        !(trimmed.is_empty()
+            || trimmed.starts_with("* ")
+            || trimmed.starts_with('#')
+            || line.starts_with(END_EVENTS_SECTION)
+            || line.starts_with(EVENTS_DATE_RANGE_HINT)
+            || line.starts_with(IGNORE_DIRECTIVE_START))
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, line) = self.lines.next()?;
+
+        if line.eq_ignore_ascii_case(START_EVENTS_SECTION) {
+            self.in_events_section = true;
+        } else if self.in_events_section
+            // only bounded readers need to parse lines to count regions
+            && self.max_regions.is_some()
+            && matches!(
+                line.parse::<EventLineType>(),
+                Ok(EventLineType::EventRegionHeader(_))
+            )
+        {
+            if self.max_regions == Some(self.regions) {
+                return None;
+            }
+            self.regions += 1;
+        }
+
+        let start_offset = self.offset_of(line);
+        let mut read = Line {
+            num: i + 1,
+            text: Cow::Borrowed(line),
+            start_offset,
+            end_offset: start_offset + line.len(),
+            ambiguous_continuation: self.after_complete_event && Self::is_continuation(line),
+        };
+        self.after_complete_event = false;
+
+        if !self.in_events_section || !Self::is_event_name(line) {
+            return Some(read);
+        }
+
+        // join the rest of a wrapped title onto its event line, as long as the event line's link isn't finished yet
+        while let Some(&(_, next)) = self.lines.peek() {
+            if !Self::is_continuation(next) {
+                break;
+            }
+            if read.text.trim_end().ends_with(')') {
+                // we flag the next line as ambiguous when we read it
+                break;
+            }
+
+            read.text = Cow::Owned(format!("{} {}", read.text.trim_end(), next.trim()));
+            read.end_offset = self.offset_of(next) + next.len();
+            self.lines.next();
+        }
+        self.after_complete_event = read.text.trim_end().ends_with(')');
+
+        Some(read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    #[test]
+    fn test_read_all_lines() {
+        let lines: Vec<Line> = Reader::new(SAMPLE).collect();
+        assert_eq!(lines.len(), SAMPLE.lines().count());
+        assert_eq!(lines[0].num(), 1);
+    }
+
+    #[test]
+    fn test_line_offsets() {
+        let contents = "## Upcoming Events\r\n\nRusty Events between 2024-10-23 - 2024-11-20 🦀\n";
+        let lines: Vec<Line> = Reader::new(contents).collect();
+
+        // the first line ends with "\r\n", which isn't part of its text
+        assert_eq!((lines[1].start_offset(), lines[1].end_offset()), (20, 20));
+        // the crab is four bytes
+        assert_eq!((lines[2].start_offset(), lines[2].end_offset()), (21, 70));
+        assert_eq!(
+            &contents[lines[2].start_offset()..lines[2].end_offset()],
+            lines[2].text()
+        );
+    }
+
+    #[test]
+    fn test_read_bounded() {
+        let lines: Vec<Line> = Reader::new_bounded(SAMPLE, 1).collect();
+
+        // the sample's second region, Asia, starts on line 197
+        let last_line = lines.last().expect("no lines read");
+        assert_eq!(last_line.num(), 196);
+        assert_eq!(last_line.text(), "");
+        assert!(lines
+            .iter()
+            .any(|line| (line.num(), line.text()) == (157, "### Virtual")));
+        assert_eq!(
+            lines[lines.len() - 2].text(),
+            "    * [**Embedded Rust Workshop**](https://www.meetup.com/vancouver-rust/events/304047664/)"
+        );
+    }
+
+    #[test]
+    fn test_join_wrapped_title() {
+        let contents = concat!(
+            "## Upcoming Events\n",
+            "### Virtual\n",
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+            "    * [**Part 4 of 4 - Hackathon Showcase:\n",
+            "    Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)\n",
+            "\n",
+        );
+        let lines: Vec<Line> = Reader::new(contents).collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[3].text(),
+            "    * [**Part 4 of 4 - Hackathon Showcase: Final Projects and Presentations**](https://www.meetup.com/women-in-rust/events/303213835/)"
+        );
+        assert_eq!(lines[3].num(), 4);
+        assert_eq!(lines[3].end_offset(), contents.len() - 2);
+        // line numbers after the joined line still match the file's
+        assert_eq!(lines[4].num(), 6);
+    }
+
+    #[test]
+    fn test_ambiguous_continuation() {
+        let contents = concat!(
+            "## Upcoming Events\n",
+            "### Virtual\n",
+            "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)\n",
+            "    * [**Hackathon Showcase**](https://www.meetup.com/women-in-rust/events/303213835/)\n",
+            "Final Projects and Presentations\n",
+        );
+        let lines: Vec<Line> = Reader::new(contents).collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[4].is_ambiguous_continuation());
+        assert!(!lines[3].is_ambiguous_continuation());
+    }
+}
@@ -9,6 +9,10 @@ const REGEX_FAIL: &str = "Failed to compile regex!";
 
 /// Regex for grabbing timestamps - we use chrono to parse this and do the actual validation
 const DATE_RE_STR: &str = r"\d{4}-\d{1,2}-\d{1,2}";
+/// Regex for an event's date, either a single date, a range for multi-day events, e.g. "2024-11-06 - 2024-11-08", or
+/// a list of dates for recurring events, e.g. "2024-10-24, 2024-10-31"
+const EVENT_DATE_RE_STR: &str =
+    r"\d{4}-\d{1,2}-\d{1,2}(?: - \d{4}-\d{1,2}-\d{1,2}|(?:, \d{4}-\d{1,2}-\d{1,2})+)?";
 
 /// Regex capture group names
 pub(crate) const START_DATE: &str = "start_date";
@@ -16,6 +20,7 @@ pub(crate) const END_DATE: &str = "end_date";
 pub(crate) const DATE: &str = "date";
 pub(crate) const LOCATION: &str = "location";
 pub(crate) const GROUP_URLS: &str = "group_urls";
+pub(crate) const TIME_NOTE: &str = "time_note";
 pub(crate) const LINK_LABEL: &str = "link_label";
 pub(crate) const LINK: &str = "link";
 
@@ -31,14 +36,44 @@ pub(crate) static EVENT_DATE_RANGE_RE: LazyLock<Regex> = LazyLock::new(|| {
 /// Regex for event date location line hint
 pub(crate) static EVENT_DATE_LOCATION_HINT_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(&format!(r"\* {}", DATE_RE_STR)).expect(REGEX_FAIL));
-/// Regex for event date location lines, e.g. "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)"
+/// Regex for event date location lines, e.g. "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)".
+/// This is tolerant of extra spaces around the pipes, which we check for separately with EVENT_DATE_LOCATION_SPACING_RE
 pub(crate) static EVENT_DATE_LOCATION_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(&format!(
-        r"\* (?<{}>{}) \| (?<{}>.+) \| (?<{}>.+)",
-        DATE, DATE_RE_STR, LOCATION, GROUP_URLS
+        r"\* (?<{}>{}) *\| *(?<{}>[^ |].*?) *\| *(?<{}>.+)",
+        DATE, EVENT_DATE_RE_STR, LOCATION, GROUP_URLS
     ))
     .expect(REGEX_FAIL)
 });
+/// Regex for the expected spacing in event date location lines, exactly one space on either side of each pipe
+pub(crate) static EVENT_DATE_LOCATION_SPACING_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\* \S+(?: - \S+|(?:, \S+)+)? \| \S(.*\S)? \| \S").expect(REGEX_FAIL)
+});
+/// Regex for the date at the start of an event date location line, used to find where a line we failed to parse went
+/// wrong
+pub(crate) static EVENT_DATE_PREFIX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^{}", EVENT_DATE_RE_STR)).expect(REGEX_FAIL));
+
+/// Regex for a time note in a virtual event's location, e.g. "Virtual (18:00 UTC)"
+pub(crate) static VIRTUAL_TIME_NOTE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^{} \((?<{}>\d{{1,2}}:\d{{2}}(?: [\w+:-]+)?)\)$",
+        VIRTUAL_LOCATION, TIME_NOTE
+    ))
+    .expect(REGEX_FAIL)
+});
+
+/// Regex for the path of a specific meetup event, e.g. "/rust-berlin/events/301234567/"
+pub(crate) static MEETUP_EVENT_PATH_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"/events/\d+/?$").expect(REGEX_FAIL));
+
+/// Regex for the path of a lu.ma page, which is just a slug, e.g. "/rust-nyc-oct"
+pub(crate) static LUMA_PATH_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^/[\w-]+/?$").expect(REGEX_FAIL));
+
+/// Regex for the path of an eventbrite event or organizer page, e.g. "/e/rust-meetup-in-paris-tickets-1037795553437"
+pub(crate) static EVENTBRITE_PATH_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^/[eo]/[\w-]+/?$").expect(REGEX_FAIL));
 
 /// Delimiter in lines like the following:
 ///  * 2024-10-24 | Virtual (Berlin, DE) | [OpenTechSchool Berlin](https://berline.rs/) + [Rust Berlin](https://www.meetup.com/rust-berlin/)
@@ -51,12 +86,24 @@ pub(crate) const EVENT_NAME_LINK_DELIM: &str = " | ";
 pub(crate) static EVENT_NAME_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"    \* (.+)").expect(REGEX_FAIL));
 
+/// Regex for raw html tags, e.g. "<script>" or "</a>". Only known tag names or tags with attributes count, so generics
+/// like "Vec<T>" aren't mistaken for html
+pub(crate) static HTML_TAG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        // known tags, e.g. "<script>" or "</a>"
+        r"(?i)</?(?:a|b|i|u|em|strong|span|div|p|br|hr|img|script|style|iframe|sub|sup|code|pre)\b[^>]*>",
+        // or any tag with an attribute, e.g. "<font color=red>"
+        r"|<[a-z][\w-]*\s+[\w-]+\s*=[^>]*>",
+    ))
+    .expect(REGEX_FAIL)
+});
+
 /// Regex for validating a markdown link like "[some link](https://www.rust-lang.org/)", this is meant to be very strict and it
 /// captures the url as the capture group
 pub(crate) static MD_LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(&format!(
-        // wow! unreadable!
-        r"^\[(?<{}>[^\]]+)\]\((?<{}>[^\)]+)\)$",
+        // wow! unreadable! labels can have escaped brackets or one level of balanced brackets, e.g. "[Rust [Berlin]]"
+        r"^\[(?<{}>(?:\\.|[^\[\]\\]|\[[^\[\]]*\])+)\]\((?<{}>[^\)]+)\)$",
         LINK_LABEL, LINK,
     ))
     .expect(REGEX_FAIL)
@@ -0,0 +1,139 @@
+use std::fmt::Write;
+
+use crate::events::EventsByRegion;
+
+/// Where the feed's events are published
+const FEED_LINK: &str = "https://this-week-in-rust.org/";
+
+/// Renders the listings as an RSS 2.0 feed with an item per listing in date order, so readers can follow the events in
+/// a feed reader. Each item links to the listing's first event, and is dated on its first day
+pub fn to_rss(events: &EventsByRegion) -> String {
+    let mut rss = String::new();
+    // writing to a string can't fail
+    let _ = writeln!(rss, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(rss, r#"<rss version="2.0">"#);
+    let _ = writeln!(rss, "<channel>");
+    let _ = writeln!(rss, "<title>This Week in Rust Events</title>");
+    let _ = writeln!(rss, "<link>{}</link>", FEED_LINK);
+    let _ = writeln!(
+        rss,
+        "<description>Upcoming Rust events from This Week in Rust</description>"
+    );
+
+    for (region, listing) in events.in_date_order() {
+        let date_location = listing.overview().date_location();
+        let links: Vec<_> = listing
+            .events()
+            .iter()
+            .flat_map(|events| events.iter())
+            .collect();
+        let title: Vec<&str> = links
+            .iter()
+            .map(|event| event.label().trim_matches('*'))
+            .collect();
+        let groups: Vec<&str> = listing
+            .overview()
+            .groups()
+            .iter()
+            .map(|group| group.label())
+            .collect();
+        let description = format!(
+            "{} | {} ({}) | {}",
+            date_location.date(),
+            date_location.location(),
+            region,
+            groups.join(", ")
+        );
+
+        let _ = writeln!(rss, "<item>");
+        let _ = writeln!(rss, "<title>{}</title>", xml_escape(&title.join(" | ")));
+        if let Some(event) = links.first() {
+            let _ = writeln!(rss, "<link>{}</link>", xml_escape(event.url().as_str()));
+            let _ = writeln!(rss, "<guid>{}</guid>", xml_escape(event.url().as_str()));
+        }
+        let _ = writeln!(
+            rss,
+            "<description>{}</description>",
+            xml_escape(&description)
+        );
+        let _ = writeln!(
+            rss,
+            "<pubDate>{}</pubDate>",
+            date_location
+                .date()
+                .sort_date()
+                .format("%a, %d %b %Y 00:00:00 +0000")
+        );
+        let _ = writeln!(rss, "</item>");
+    }
+
+    let _ = writeln!(rss, "</channel>");
+    let _ = writeln!(rss, "</rss>");
+    rss
+}
+
+/// Escapes the characters with special meaning in XML text
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lint::EventSectionLinter;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    const SAMPLE: &str = include_str!("../test/570.md");
+
+    /// Checks every tag is closed in the order it was opened, and there's no unescaped markup in text
+    fn assert_well_formed(xml: &str) {
+        let mut open_tags: Vec<&str> = Vec::new();
+        let mut rest = xml;
+        while let Some(start) = rest.find('<') {
+            assert!(
+                !rest[..start].contains(['>', '"']),
+                "unescaped text before '{}'",
+                &rest[start..]
+            );
+            let end = rest[start..].find('>').expect("unclosed tag") + start;
+            let tag = &rest[start + 1..end];
+            match tag.strip_prefix('/') {
+                _ if tag.starts_with('?') => (),
+                Some(name) => assert_eq!(open_tags.pop(), Some(name)),
+                None => open_tags.push(tag.split_whitespace().next().unwrap_or(tag)),
+            }
+            rest = &rest[end + 1..];
+        }
+        assert!(open_tags.is_empty(), "unclosed tags: {:?}", open_tags);
+    }
+
+    #[test]
+    fn test_to_rss() -> TestResult {
+        let mut linter = EventSectionLinter::default();
+        linter.lint(SAMPLE)?;
+        let rss = to_rss(linter.events());
+
+        assert_well_formed(&rss);
+        assert_eq!(rss.matches("<item>").count(), 45);
+        assert!(rss.contains("<title>Rust Hack &amp; Learn October 2024</title>"));
+        assert!(
+            rss.contains("<link>https://www.meetup.com/women-in-rust/events/303213835/</link>\n")
+        );
+        assert!(rss.contains("<pubDate>Thu, 24 Oct 2024 00:00:00 +0000</pubDate>"));
+        assert!(rss
+            .contains("<description>2024-10-24 | Virtual (Virtual) | Women in Rust</description>"));
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use crate::constants::*;
+
+/// Builds an events section for tests, so tests don't need to write out the whole section by hand. Regions and events
+/// are written in the order they're added, followed by the footer
+#[derive(Debug, Default)]
+pub(crate) struct SectionBuilder {
+    preamble: String,
+    date_range: Option<(String, String)>,
+    body: String,
+    /// Whether we're in a region that needs a blank line to end it
+    in_region: bool,
+}
+
+impl SectionBuilder {
+    /// A builder for a section with the same date range as the sample newsletter
+    pub(crate) fn new() -> Self {
+        Self::default().date_range("2024-10-23", "2024-11-20")
+    }
+
+    /// Text before the events section, the linter should skip over this
+    pub(crate) fn preamble(mut self, preamble: &str) -> Self {
+        self.preamble = preamble.to_owned();
+        self
+    }
+
+    pub(crate) fn date_range(mut self, start: &str, end: &str) -> Self {
+        self.date_range = Some((start.to_owned(), end.to_owned()));
+        self
+    }
+
+    /// Starts a new region, events added after this are in it
+    pub(crate) fn region(mut self, region: &str) -> Self {
+        self.end_region();
+        // writing to a string can't fail
+        let _ = writeln!(self.body, "{}{}", EVENT_REGION_HEADER, region);
+        self.in_region = true;
+        self
+    }
+
+    /// Adds an event to the current region, e.g. an overview of
+    /// "2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)" and a detail of
+    /// "[**Hackathon Showcase**](https://www.meetup.com/women-in-rust/events/303213835/)"
+    pub(crate) fn event(mut self, overview: &str, detail: &str) -> Self {
+        let _ = writeln!(
+            self.body,
+            "* {}\n{}* {}",
+            overview, EVENT_NAME_INDENT, detail
+        );
+        self
+    }
+
+    /// Adds lines as they are after the regions so far, for anything the builder can't make, like malformed lines
+    pub(crate) fn raw(mut self, lines: &str) -> Self {
+        self.end_region();
+        self.body.push_str(lines);
+        self
+    }
+
+    pub(crate) fn build(mut self) -> String {
+        self.end_region();
+
+        let mut text = self.preamble;
+        let _ = writeln!(text, "{}\n", START_EVENTS_SECTION);
+        if let Some((start, end)) = self.date_range {
+            let _ = writeln!(
+                text,
+                "{} {} - {} {}\n",
+                EVENTS_DATE_RANGE_HINT, start, end, CRAB_EMOJI
+            );
+        }
+        text.push_str(&self.body);
+        let _ = writeln!(text, "{}", EVENTS_FOOTER);
+        text
+    }
+
+    fn end_region(&mut self) {
+        if self.in_region {
+            self.body.push('\n');
+            self.in_region = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lint::EventSectionLinter;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_builder_is_lint_clean() -> TestResult {
+        let text = SectionBuilder::new()
+            .preamble("some pre events section text\n")
+            .region("Virtual")
+            .event(
+                "2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "[**Hackathon Showcase**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .region("Europe")
+            .event(
+                "2024-10-24 | Berlin, DE | [Rust Berlin](https://www.meetup.com/rust-berlin/)",
+                "[**Rust Hack and Learn**](https://www.meetup.com/rust-berlin/events/298633271/)",
+            )
+            .event(
+                "2024-10-29 | Aarhus, DK | [Rust Aarhus](https://www.meetup.com/rust-aarhus/)",
+                "[**Hack Night**](https://www.meetup.com/rust-aarhus/events/303479865/)",
+            )
+            .build();
+
+        let mut linter = EventSectionLinter::default();
+        linter.lint(&text)?;
+        assert!(linter.warnings().is_empty());
+        assert_eq!(
+            linter.events().counts(),
+            vec![("Virtual", 1), ("Europe", 2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_layout() {
+        let text = SectionBuilder::new()
+            .region("Virtual")
+            .event(
+                "2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "[**Hackathon Showcase**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            )
+            .build();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[..7],
+            [
+                "## Upcoming Events",
+                "",
+                "Rusty Events between 2024-10-23 - 2024-11-20 🦀",
+                "",
+                "### Virtual",
+                "* 2024-10-24 | Virtual | [Women in Rust](https://www.meetup.com/women-in-rust/)",
+                "    * [**Hackathon Showcase**](https://www.meetup.com/women-in-rust/events/303213835/)",
+            ]
+        );
+        assert_eq!(lines[7], "");
+        assert!(lines[8].starts_with(END_EVENTS_SECTION));
+    }
+}